@@ -0,0 +1,149 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{parse::lines, Answer, AocError, ParseError};
+
+/// Name of the answers file, stored in the input directory next to the inputs it describes.
+pub const FILE_NAME: &str = "answers.txt";
+
+/// Name used for the main `dayN.txt` input.
+pub const MAIN_INPUT: &str = "main";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+}
+
+impl Key {
+    pub fn new(day: u8, part: u8, input: Option<&str>) -> Self {
+        Key {
+            day,
+            part,
+            input: input.unwrap_or(MAIN_INPUT).to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    New,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Verdict::New => write!(f, "new"),
+        }
+    }
+}
+
+/// Known-good answers, one per line as `DAY PART INPUT ANSWER`, e.g. `16 2 example2 64`.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<Key, Answer>,
+}
+
+impl Answers {
+    pub fn path(dir: &Path) -> PathBuf {
+        dir.join(FILE_NAME)
+    }
+
+    /// Loads the answers stored in `dir`, starting out empty if there are none yet.
+    pub fn load(dir: &Path) -> Result<Self, AocError> {
+        let path = Self::path(dir);
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                Self::from_text(&text).map_err(|source| AocError::InvalidFile { path, source })
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(source) => Err(AocError::Io { path, source }),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        for line in lines(text).filter(|line| !line.text.starts_with('#')) {
+            let fields: Vec<&str> = line.text.split_whitespace().collect();
+            let [day, part, input, answer] = fields[..] else {
+                return Err(line.error("expected `DAY PART INPUT ANSWER`"));
+            };
+            let key = Key {
+                day: line.parse(day)?,
+                part: line.parse(part)?,
+                input: input.to_string(),
+            };
+            if answers.insert(key, answer.to_string()).is_some() {
+                return Err(line.error("duplicate answer"));
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn to_text(&self) -> String {
+        self.answers
+            .iter()
+            .map(|(key, answer)| format!("{} {} {} {answer}\n", key.day, key.part, key.input))
+            .collect()
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<(), AocError> {
+        let path = Self::path(dir);
+        fs::write(&path, self.to_text()).map_err(|source| AocError::Io { path, source })
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Answer> {
+        self.answers.get(key)
+    }
+
+    pub fn insert(&mut self, key: Key, answer: Answer) {
+        self.answers.insert(key, answer);
+    }
+
+    /// Compares `answer` against the stored one. Answers are stored in a single
+    /// whitespace-separated field, so empty answers are never recorded.
+    pub fn check(&self, key: &Key, answer: &str) -> Verdict {
+        match self.get(key) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_answers() {
+        let text = "# day part input answer\n1 1 main 11\n23 2 main aj,ds,gg\n";
+        let answers = Answers::from_text(text).unwrap();
+        assert_eq!(Verdict::Pass, answers.check(&Key::new(1, 1, None), "11"));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "11".to_string()
+            },
+            answers.check(&Key::new(1, 1, None), "12")
+        );
+        assert_eq!(
+            Verdict::New,
+            answers.check(&Key::new(1, 1, Some("example")), "11")
+        );
+        assert_eq!("1 1 main 11\n23 2 main aj,ds,gg\n", answers.to_text());
+
+        let err = Answers::from_text("1 1 main 11\n1 1 main 12").unwrap_err();
+        assert_eq!(Some(2), err.line);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+    time::Duration,
+};
+
+use super::{Phase, Report, Stats, Timing};
+use crate::{parse::lines, AocError, ParseError};
+
+/// Default history file, relative to the current directory. Timings only make sense on
+/// the machine that produced them, so it is not checked in.
+pub const FILE_NAME: &str = "bench_history.txt";
+
+/// Regressions are reported when the median is this many percent slower than the best.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub input: String,
+    pub phase: Phase,
+    pub revision: String,
+    pub stats: Stats,
+}
+
+/// How a timing compares to the best median recorded at another revision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub best: Duration,
+    pub best_revision: String,
+    /// Change of the median relative to the best, in percent.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Benchmark results, one per line as `DAY INPUT PHASE REVISION MIN_NS MEDIAN_NS P95_NS`.
+/// Each revision keeps only its latest result.
+#[derive(Debug, Default)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history at `path`, starting out empty if there is none yet.
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let path = path.to_path_buf();
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                Self::from_text(&text).map_err(|source| AocError::InvalidFile { path, source })
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(source) => Err(AocError::Io { path, source }),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let mut entries = Vec::new();
+        for line in lines(text).filter(|line| !line.text.starts_with('#')) {
+            let fields: Vec<&str> = line.text.split_whitespace().collect();
+            let [day, input, phase, revision, min, median, p95] = fields[..] else {
+                return Err(
+                    line.error("expected `DAY INPUT PHASE REVISION MIN_NS MEDIAN_NS P95_NS`")
+                );
+            };
+            let nanos = |field| line.parse(field).map(Duration::from_nanos);
+            entries.push(Entry {
+                day: line.parse(day)?,
+                input: input.to_string(),
+                phase: line.parse(phase)?,
+                revision: revision.to_string(),
+                stats: Stats {
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    p95: nanos(p95)?,
+                },
+            });
+        }
+        Ok(History { entries })
+    }
+
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{} {} {} {} {} {} {}\n",
+                    entry.day,
+                    entry.input,
+                    entry.phase,
+                    entry.revision,
+                    entry.stats.min.as_nanos(),
+                    entry.stats.median.as_nanos(),
+                    entry.stats.p95.as_nanos()
+                )
+            })
+            .collect()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), AocError> {
+        fs::write(path, self.to_text()).map_err(|source| AocError::Io {
+            path: PathBuf::from(path),
+            source,
+        })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Records the report for `revision`, replacing earlier results from the same revision.
+    pub fn record(&mut self, report: &Report, revision: &str) {
+        for timing in &report.timings {
+            let entry = Entry {
+                day: report.day,
+                input: report.input.clone(),
+                phase: timing.phase,
+                revision: revision.to_string(),
+                stats: timing.stats,
+            };
+            match self.entries.iter_mut().find(|old| {
+                (old.day, &old.input, old.phase, &old.revision)
+                    == (entry.day, &entry.input, entry.phase, &entry.revision)
+            }) {
+                Some(old) => *old = entry,
+                None => self.entries.push(entry),
+            }
+        }
+    }
+
+    /// Compares `timing` with the best median of other revisions. `threshold` is in percent.
+    pub fn compare(
+        &self,
+        report: &Report,
+        timing: &Timing,
+        revision: &str,
+        threshold: f64,
+    ) -> Option<Comparison> {
+        let best = self
+            .entries
+            .iter()
+            .filter(|entry| {
+                entry.day == report.day
+                    && entry.input == report.input
+                    && entry.phase == timing.phase
+                    && entry.revision != revision
+            })
+            .min_by_key(|entry| entry.stats.median)?;
+        let change =
+            (timing.stats.median.as_secs_f64() / best.stats.median.as_secs_f64() - 1.0) * 100.0;
+        Some(Comparison {
+            best: best.stats.median,
+            best_revision: best.revision.clone(),
+            change,
+            regressed: change > threshold,
+        })
+    }
+}
+
+/// The current git revision, suffixed with `-dirty` when there are uncommitted changes.
+pub fn revision() -> String {
+    let git = |args: &[&str]| {
+        let output = Command::new("git").args(args).output().ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match (
+        git(&["rev-parse", "--short", "HEAD"]),
+        git(&["status", "--porcelain", "--untracked-files=no"]),
+    ) {
+        (Some(rev), Some(status)) if !status.is_empty() => format!("{rev}-dirty"),
+        (Some(rev), _) => rev,
+        (None, _) => "unknown".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(median_ms: u64) -> Report {
+        let ms = Duration::from_millis(median_ms);
+        Report {
+            day: 20,
+            input: "main".to_string(),
+            timings: vec![Timing {
+                phase: Phase::Part(2),
+                stats: Stats {
+                    min: ms,
+                    median: ms,
+                    p95: ms,
+                },
+            }],
+        }
+    }
+
+    #[test]
+    fn test_regression_against_best() {
+        let mut history = History::default();
+        history.record(&report(120), "aaaaaaa");
+        history.record(&report(100), "bbbbbbb");
+        history.record(&report(200), "ccccccc");
+        history.record(&report(105), "ccccccc");
+        let history = History::from_text(&history.to_text()).unwrap();
+        assert_eq!(3, history.entries().len());
+
+        let slow = report(115);
+        let comparison = history
+            .compare(&slow, &slow.timings[0], "ddddddd", 10.0)
+            .unwrap();
+        assert_eq!("bbbbbbb", comparison.best_revision);
+        assert!(comparison.regressed);
+
+        let comparison = history
+            .compare(&slow, &slow.timings[0], "bbbbbbb", 10.0)
+            .unwrap();
+        assert_eq!("ccccccc", comparison.best_revision);
+        assert!(!comparison.regressed);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{AocError, Solution};
+
+pub mod history;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+/// What is being timed: parsing the input, or solving a part from the parsed input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Phase::Part)
+                .ok_or(format!("unknown phase `{s}`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Nearest-rank statistics of the samples, which must not be empty.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let rank = |p: usize| samples[(samples.len() * p).div_ceil(100).max(1) - 1];
+        Stats {
+            min: samples[0],
+            median: rank(50),
+            p95: rank(95),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Timings of one day, parse first followed by the requested parts.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub input: String,
+    pub timings: Vec<Timing>,
+}
+
+fn measure<T>(
+    config: &Config,
+    mut f: impl FnMut() -> Result<T, AocError>,
+) -> Result<Stats, AocError> {
+    for _ in 0..config.warmup {
+        black_box(f()?);
+    }
+    let mut samples = Vec::with_capacity(config.iterations as usize);
+    for _ in 0..config.iterations.max(1) {
+        let before = Instant::now();
+        let result = black_box(f()?);
+        samples.push(before.elapsed());
+        drop(result);
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Times parsing and each of `parts` separately. Parts are run against a single parse.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[u8],
+    config: &Config,
+) -> Result<Vec<Timing>, AocError> {
+    let mut timings = vec![Timing {
+        phase: Phase::Parse,
+        stats: measure(config, || S::read(black_box(input)))?,
+    }];
+    let parsed = S::read(input)?;
+    for &part in parts {
+        timings.push(Timing {
+            phase: Phase::Part(part),
+            stats: measure(config, || S::run(part, black_box(&parsed)))?,
+        });
+    }
+    Ok(timings)
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from("\"");
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            ch if ch.is_control() => json.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Machine-readable form of the reports, with durations in nanoseconds.
+pub fn to_json(config: &Config, reports: &[Report]) -> String {
+    let reports: Vec<String> = reports
+        .iter()
+        .map(|report| {
+            let timings: Vec<String> = report
+                .timings
+                .iter()
+                .map(|timing| {
+                    format!(
+                        "{{\"phase\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                        json_string(&timing.phase.to_string()),
+                        timing.stats.min.as_nanos(),
+                        timing.stats.median.as_nanos(),
+                        timing.stats.p95.as_nanos()
+                    )
+                })
+                .collect();
+            format!(
+                "    {{\"day\": {}, \"input\": {}, \"timings\": [\n      {}\n    ]}}",
+                report.day,
+                json_string(&report.input),
+                timings.join(",\n      ")
+            )
+        })
+        .collect();
+    format!(
+        "{{\n  \"warmup\": {},\n  \"iterations\": {},\n  \"reports\": [\n{}\n  ]\n}}\n",
+        config.warmup,
+        config.iterations,
+        reports.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(10), stats.median);
+        assert_eq!(Duration::from_millis(19), stats.p95);
+    }
+
+    #[test]
+    fn test_json_report() {
+        let stats = Stats::from_samples(vec![Duration::from_nanos(5)]);
+        let report = Report {
+            day: 1,
+            input: "ex\"1".to_string(),
+            timings: vec![Timing {
+                phase: Phase::Part(2),
+                stats,
+            }],
+        };
+        let json = to_json(&Config::default(), &[report]);
+        assert!(json.contains("\"input\": \"ex\\\"1\""));
+        assert!(
+            json.contains("{\"phase\": \"part2\", \"min_ns\": 5, \"median_ns\": 5, \"p95_ns\": 5}")
+        );
+    }
+}
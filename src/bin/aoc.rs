@@ -0,0 +1,1054 @@
+use std::{
+    env, fs,
+    io::{self, BufRead, Write},
+    path::PathBuf,
+    process::ExitCode,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2024::{
+    answers::{self, Answers, Key, Verdict},
+    bench::{
+        self,
+        history::{self, History},
+        Config, Report,
+    },
+    days::{
+        self,
+        day15::{
+            replay::{self, Replay},
+            Day15,
+        },
+        day17::{
+            assembler,
+            debugger::{Breakpoint, Command, Debugger, Stop, STEP_LIMIT},
+            decompile,
+            instruction::{self, Register},
+            symbolic, Day17, Registers,
+        },
+        day9::{
+            simulator::{self, Disk},
+            strategy::{Policy, Strategy},
+            Day9,
+        },
+        Puzzle, PUZZLES,
+    },
+    fetch::{Fetcher, SESSION_VAR},
+    input::{InputSource, Inputs, INPUT_DIR_VAR},
+    AocError, Solution,
+};
+
+const USAGE: &str =
+    "Usage: aoc run <DAY...|all> [--part 1|2] [--input-dir <DIR|->] [--input <NAME>]
+       aoc verify <DAY...|all> [--part 1|2] [--input-dir <DIR>] [--input <NAME>] [--update]
+       aoc bench <DAY...|all> [--part 1|2] [--input-dir <DIR|->] [--input <NAME>]
+                 [--warmup <N>] [--iterations <N>] [--json <FILE|->]
+                 [--history <FILE>] [--threshold <PERCENT>]
+       aoc fetch <DAY...|all> [--input-dir <DIR>]
+       aoc day9 <layout|compact> [--part 1|2] [--input-dir <DIR|->] [--input <NAME>]
+                 [--strategy <NAME>] [--until-fixpoint]
+       aoc day15 <replay|stats> [--part 1|2] [--input-dir <DIR|->] [--input <NAME>]
+                 [--every <N>] [--delay <MS>] [--frames <FILE>] [--no-color]
+       aoc day17 <disasm|debug|trace|solve> [--input-dir <DIR|->] [--input <NAME>]
+                 [--set <REG>=<N>] [--break <BP>] [--watch <REG>] [--trace <FILE>]
+                 [--output <VALUES>]
+       aoc day17 asm <FILE|-> [--set <REG>=<N>]
+
+Commands:
+    run      Print the answers
+    verify   Check the answers against <DIR>/answers.txt, recording new ones
+    bench    Time parsing and each part separately, reporting min/median/p95 and
+             regressions against earlier revisions
+    fetch    Download missing inputs using the session cookie in $AOC_SESSION
+    day9     `layout` prints the disk block by block after compacting it, checking
+             the checksum against the one the solution computes. `compact`
+             compares the checksum, moves and fragmentation left by each strategy
+    day15    `replay` draws the warehouse after every move (part 1 unless --part 2).
+             Press Enter to pause or resume, `+` Enter to speed up, `-` Enter to
+             slow down and `q` Enter to stop. `stats` counts the moves that
+             were blocked and the boxes pushed
+    day17    Tools for the day 17 program: `disasm` lists its instructions and
+             decompiles its loop, `debug` steps through it interactively (type
+             `help` at the prompt) and `trace` runs it, writing every step.
+             `solve` finds the smallest A that makes it print --output (default:
+             the program itself). `asm` turns mnemonics back into a day 17 input
+
+Options:
+    -p, --part        Only run the given part
+    -i, --input-dir   Directory to read dayN.txt from (default: $AOC_INPUT_DIR or inputs/),
+                      `-` reads a single day's input from stdin
+    -n, --input       Use the named input dayN.NAME.txt, e.g. `example2`
+        --update      Overwrite stored answers that no longer match
+        --warmup      Untimed runs before measuring (default: 3)
+        --iterations  Timed runs per phase (default: 20)
+        --json        Also write a JSON report to FILE, `-` prints only the JSON
+        --history     File the results are recorded in per git revision
+                      (default: bench_history.txt)
+        --threshold   Fail when a median is this many percent slower than the best
+                      median of another revision (default: 10)
+        --every       Draw day 15 every N moves (default: 1)
+        --delay       Milliseconds between day 15 frames (default: 100)
+        --frames      Write the day 15 frames to FILE in the puzzle's format instead
+                      of playing them
+        --no-color    Play day 15 without ANSI colours
+        --set         Start day 17 with a register changed, e.g. `A=117440`,
+                      or set it in the input `asm` prints
+        --break       Day 17 breakpoint: an address (`ip=12`) or a register
+                      condition (`A==0`), repeatable
+        --watch       Stop day 17 whenever the register changes, repeatable
+        --trace       Write the day 17 execution trace to FILE (default for
+                      `trace`: stdout)
+        --output      Comma-separated values for `day17 solve` to look for
+        --strategy    How day 9 compacts the disk: blocks (part 1), first (part 2),
+                      best, worst or next fit
+        --until-fixpoint
+                      Keep moving day 9 files until a pass moves none, rather than
+                      moving each once
+
+Examples:
+    aoc run 16 --part 2
+    aoc run 16 --input example2
+    aoc run 1 5 9
+    aoc run all
+    aoc verify all
+    aoc bench 9 --iterations 100 --json bench.json
+    aoc day9 layout --part 2 --input example
+    aoc day9 compact --strategy best --until-fixpoint
+    aoc day15 replay --part 2 --input example --every 10
+    aoc day15 replay --input example2 --frames frames.txt
+    aoc day15 stats --part 2
+    aoc day17 disasm
+    aoc day17 debug --set A=117440 --break A==0
+    aoc day17 trace --trace trace.tsv
+    aoc day17 solve --output 3,1,0
+    aoc day17 asm quine.asm --set A=2024 > inputs/day17.quine.txt";
+
+struct RunArgs {
+    puzzles: Vec<&'static Puzzle>,
+    parts: Vec<u8>,
+    inputs: Inputs,
+    input_name: Option<String>,
+    update: bool,
+    bench: Config,
+    json: Option<String>,
+    history: PathBuf,
+    threshold: f64,
+}
+
+fn parse_count(name: &str, value: Option<&String>) -> Result<u32, String> {
+    let value = value.ok_or(format!("{name} expects a value"))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {name}: {value}"))
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunArgs, String> {
+    let mut puzzles = Vec::new();
+    let mut parts = vec![1, 2];
+    let mut input_dir = None;
+    let mut input_name = None;
+    let mut update = false;
+    let mut bench = Config::default();
+    let mut json = None;
+    let mut history = PathBuf::from(history::FILE_NAME);
+    let mut threshold = history::DEFAULT_THRESHOLD;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("--part expects a value")?;
+                parts = match part.as_str() {
+                    "1" => vec![1],
+                    "2" => vec![2],
+                    _ => return Err(format!("Invalid part: {part}")),
+                };
+            }
+            "--input-dir" | "-i" => {
+                input_dir = Some(args.next().ok_or("--input-dir expects a value")?);
+            }
+            "--input" | "-n" => {
+                input_name = Some(args.next().ok_or("--input expects a value")?.clone());
+            }
+            "--update" => update = true,
+            "--warmup" => bench.warmup = parse_count("--warmup", args.next())?,
+            "--iterations" => bench.iterations = parse_count("--iterations", args.next())?,
+            "--json" => json = Some(args.next().ok_or("--json expects a value")?.clone()),
+            "--history" => {
+                history = PathBuf::from(args.next().ok_or("--history expects a value")?);
+            }
+            "--threshold" => {
+                let value = args.next().ok_or("--threshold expects a value")?;
+                threshold = value
+                    .trim_end_matches('%')
+                    .parse()
+                    .map_err(|_| format!("Invalid value for --threshold: {value}"))?;
+            }
+            "all" => puzzles.extend(PUZZLES.iter()),
+            day => {
+                let day: u8 = day
+                    .trim_start_matches("day")
+                    .parse()
+                    .map_err(|_| format!("Invalid day: {day}"))?;
+                puzzles.push(days::find(day).ok_or(format!("Day {day} is not solved yet"))?);
+            }
+        }
+    }
+    if puzzles.is_empty() {
+        return Err("No days given".to_string());
+    }
+    puzzles.sort_by_key(|puzzle| puzzle.day);
+    puzzles.dedup_by_key(|puzzle| puzzle.day);
+
+    let mut inputs = Inputs::new(input_dir.map(String::as_str));
+    if let Some(fetcher) = Fetcher::from_env() {
+        inputs = inputs.with_fetcher(fetcher);
+    }
+    if *inputs.source() == InputSource::Stdin && puzzles.len() > 1 {
+        return Err(format!(
+            "Reading from stdin (via --input-dir or {INPUT_DIR_VAR}) needs exactly one day"
+        ));
+    }
+
+    Ok(RunArgs {
+        puzzles,
+        parts,
+        inputs,
+        input_name,
+        update,
+        bench,
+        json,
+        history,
+        threshold,
+    })
+}
+
+struct Row {
+    day: u8,
+    answers: [String; 2],
+    elapsed: Duration,
+}
+
+fn run_puzzle(puzzle: &Puzzle, args: &RunArgs) -> Result<Row, AocError> {
+    let input = args.inputs.read(puzzle.day, args.input_name.as_deref())?;
+    let parts = &args.parts;
+    let mut answers = [String::from("-"), String::from("-")];
+
+    let before = Instant::now();
+    let solved = (puzzle.solve)(&input, parts)?;
+    let elapsed = before.elapsed();
+
+    for (&part, answer) in parts.iter().zip(solved) {
+        answers[part as usize - 1] = answer;
+    }
+
+    Ok(Row {
+        day: puzzle.day,
+        answers,
+        elapsed,
+    })
+}
+
+fn print_table(rows: &[Row]) {
+    let width = |part: usize| {
+        rows.iter()
+            .map(|row| row.answers[part].len())
+            .chain([6])
+            .max()
+            .unwrap()
+    };
+    let (w1, w2) = (width(0), width(1));
+
+    println!("{:>3}  {:<w1$}  {:<w2$}  Time", "Day", "Part 1", "Part 2");
+    for row in rows {
+        println!(
+            "{:>3}  {:<w1$}  {:<w2$}  {:?}",
+            row.day, row.answers[0], row.answers[1], row.elapsed
+        );
+    }
+    let total: Duration = rows.iter().map(|row| row.elapsed).sum();
+    println!("Total time: {total:?}");
+}
+
+fn run(args: &[String]) -> ExitCode {
+    let args = match parse_run_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut failed = false;
+    for puzzle in &args.puzzles {
+        match run_puzzle(puzzle, &args) {
+            Ok(row) => rows.push(row),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+            }
+        }
+    }
+    if !rows.is_empty() {
+        print_table(&rows);
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: &[String]) -> ExitCode {
+    let args = match parse_run_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let Some(dir) = args.inputs.primary_dir() else {
+        eprintln!("error: verify needs an input directory, not stdin");
+        return ExitCode::from(2);
+    };
+    let mut answers = match Answers::load(dir) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut skipped, mut new, mut changed) = (0, 0, 0, 0, false);
+    for puzzle in &args.puzzles {
+        let row = match run_puzzle(puzzle, &args) {
+            Ok(row) => row,
+            // Not every day has its input checked in, which is no reason to fail.
+            Err(AocError::MissingInput { .. }) => {
+                let input = args.input_name.as_deref().unwrap_or(answers::MAIN_INPUT);
+                println!("{} {input}: skipped, no input", puzzle.name());
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed += 1;
+                continue;
+            }
+        };
+        for &part in &args.parts {
+            let answer = &row.answers[part as usize - 1];
+            let key = Key::new(puzzle.day, part, args.input_name.as_deref());
+            if answer.is_empty() {
+                println!("{} part {part} {}: no answer", puzzle.name(), key.input);
+                continue;
+            }
+            let verdict = answers.check(&key, answer);
+            println!(
+                "{} part {part} {}: {verdict}  {answer}",
+                puzzle.name(),
+                key.input
+            );
+            match verdict {
+                Verdict::Pass => passed += 1,
+                Verdict::Fail { .. } if !args.update => failed += 1,
+                Verdict::Fail { .. } | Verdict::New => {
+                    new += 1;
+                    changed = true;
+                    answers.insert(key, answer.clone());
+                }
+            }
+        }
+    }
+    println!("{passed} passed, {failed} failed, {skipped} skipped, {new} recorded");
+
+    if changed {
+        if let Err(e) = answers.save(dir) {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+        println!("Updated {}", Answers::path(dir).display());
+    }
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn bench_puzzle(puzzle: &Puzzle, args: &RunArgs) -> Result<Report, AocError> {
+    let input_name = args.input_name.as_deref();
+    let input = args.inputs.read(puzzle.day, input_name)?;
+    Ok(Report {
+        day: puzzle.day,
+        input: input_name.unwrap_or(answers::MAIN_INPUT).to_string(),
+        timings: (puzzle.bench)(&input, &args.parts, &args.bench)?,
+    })
+}
+
+fn print_bench_table(reports: &[Report], history: &History, revision: &str, threshold: f64) {
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  vs best",
+        "Day", "Phase", "Min", "Median", "p95"
+    );
+    for report in reports {
+        for timing in &report.timings {
+            let stats = timing.stats;
+            let comparison = match history.compare(report, timing, revision, threshold) {
+                Some(c) if c.regressed => format!(
+                    "{:+.1}% REGRESSION (best {:.2?} at {})",
+                    c.change, c.best, c.best_revision
+                ),
+                Some(c) => format!("{:+.1}%", c.change),
+                None => String::from("-"),
+            };
+            println!(
+                "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {comparison}",
+                report.day,
+                timing.phase.to_string(),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.p95)
+            );
+        }
+    }
+}
+
+fn bench(args: &[String]) -> ExitCode {
+    let args = match parse_run_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let mut history = match History::load(&args.history) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let revision = history::revision();
+
+    let mut reports = Vec::new();
+    let mut failed = false;
+    for puzzle in &args.puzzles {
+        match bench_puzzle(puzzle, &args) {
+            Ok(report) => reports.push(report),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    let json = bench::to_json(&args.bench, &reports);
+    match args.json.as_deref() {
+        Some("-") => print!("{json}"),
+        Some(path) => {
+            print_bench_table(&reports, &history, &revision, args.threshold);
+            if let Err(e) = fs::write(path, json) {
+                eprintln!("error: could not write {path}: {e}");
+                failed = true;
+            }
+        }
+        None => print_bench_table(&reports, &history, &revision, args.threshold),
+    }
+
+    let regressions = reports
+        .iter()
+        .flat_map(|report| report.timings.iter().map(move |timing| (report, timing)))
+        .filter_map(|(report, timing)| history.compare(report, timing, &revision, args.threshold))
+        .filter(|comparison| comparison.regressed)
+        .count();
+    if regressions > 0 {
+        eprintln!(
+            "{regressions} timing(s) more than {}% slower than their best",
+            args.threshold
+        );
+        failed = true;
+    }
+
+    for report in &reports {
+        history.record(report, &revision);
+    }
+    if let Err(e) = history.save(&args.history) {
+        eprintln!("error: {e}");
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(args: &[String]) -> ExitCode {
+    let args = match parse_run_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if Fetcher::from_env().is_none() {
+        eprintln!("error: {SESSION_VAR} is not set");
+        return ExitCode::FAILURE;
+    }
+
+    let mut failed = false;
+    for puzzle in &args.puzzles {
+        if let Some(path) = args.inputs.find(puzzle.day, None) {
+            println!("{}: cached at {}", puzzle.name(), path.display());
+            continue;
+        }
+        match args.inputs.read(puzzle.day, None) {
+            Ok(input) => println!("{}: downloaded {} bytes", puzzle.name(), input.len()),
+            Err(e) => {
+                eprintln!("error: {e}");
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+struct Day9Args {
+    run: RunArgs,
+    policy: Option<Policy>,
+    until_fixpoint: bool,
+}
+
+fn parse_day9_args(args: &[String]) -> Result<Day9Args, String> {
+    let mut run_args = vec![String::from("9")];
+    let mut policy = None;
+    let mut until_fixpoint = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strategy" => {
+                policy = Some(args.next().ok_or("--strategy expects a value")?.parse()?);
+            }
+            "--until-fixpoint" => until_fixpoint = true,
+            _ => run_args.push(arg.clone()),
+        }
+    }
+
+    Ok(Day9Args {
+        run: parse_run_args(&run_args)?,
+        policy,
+        until_fixpoint,
+    })
+}
+
+fn disk_map(args: &Day9Args) -> Result<Vec<i64>, AocError> {
+    let input = args.run.inputs.read(9, args.run.input_name.as_deref())?;
+    Day9::read(&input)
+}
+
+/// Without a strategy, prints the layout for each part after checking it against
+/// the solution.
+fn layout(args: &Day9Args) -> Result<(), AocError> {
+    let disk_map = disk_map(args)?;
+    if args.policy.is_some() || args.until_fixpoint {
+        let mut disk = Disk::new(&disk_map);
+        let report = disk.compact(Strategy {
+            policy: args.policy.unwrap_or_default(),
+            until_fixpoint: args.until_fixpoint,
+        });
+        println!("{disk}");
+        eprintln!("{}: checksum {}", report.strategy, report.checksum);
+        return Ok(());
+    }
+    for &part in &args.run.parts {
+        let disk = simulator::simulate(&disk_map, part).map_err(|source| AocError::Unsolvable {
+            day: 9,
+            part,
+            source,
+        })?;
+        println!("{disk}");
+        eprintln!(
+            "Part {part}: checksum {} matches the solution",
+            disk.checksum()
+        );
+    }
+    Ok(())
+}
+
+/// Compares the compaction strategies, or only those picked by the options.
+fn compact(args: &Day9Args) -> Result<(), AocError> {
+    let disk_map = disk_map(args)?;
+    let policies = args
+        .policy
+        .map_or(Policy::ALL.to_vec(), |policy| vec![policy]);
+    let passes: &[bool] = if args.until_fixpoint {
+        &[true]
+    } else {
+        &[false, true]
+    };
+
+    println!(
+        "{:<24}  {:>15}  {:>6}  {:>6}  {:>6}  {:>11}  {:>11}",
+        "Strategy", "Checksum", "Moves", "Passes", "Gaps", "Free blocks", "Split files"
+    );
+    for policy in policies {
+        for &until_fixpoint in passes {
+            if policy == Policy::Blocks && until_fixpoint {
+                continue;
+            }
+            let report = Disk::new(&disk_map).compact(Strategy {
+                policy,
+                until_fixpoint,
+            });
+            let fragmentation = report.fragmentation;
+            println!(
+                "{:<24}  {:>15}  {:>6}  {:>6}  {:>6}  {:>11}  {:>11}",
+                report.strategy.to_string(),
+                report.checksum,
+                report.moves,
+                report.passes,
+                fragmentation.gaps,
+                fragmentation.free_blocks,
+                fragmentation.split_files
+            );
+        }
+    }
+    Ok(())
+}
+
+fn day9(args: &[String]) -> ExitCode {
+    let Some((command, rest)) = args.split_first() else {
+        eprintln!("day9 expects a command\n\n{USAGE}");
+        return ExitCode::from(2);
+    };
+    let args = match parse_day9_args(rest) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = match command.as_str() {
+        "layout" => layout(&args),
+        "compact" => compact(&args),
+        _ => {
+            eprintln!("Unknown day9 command: {command}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct Day15Args {
+    run: RunArgs,
+    every: usize,
+    delay: Duration,
+    frames: Option<PathBuf>,
+    colour: bool,
+}
+
+fn parse_day15_args(args: &[String]) -> Result<Day15Args, String> {
+    let mut run_args = vec![String::from("15")];
+    let mut every = 1;
+    let mut delay = Duration::from_millis(100);
+    let mut frames = None;
+    let mut colour = true;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--every" => every = parse_count("--every", args.next())?.max(1) as usize,
+            "--delay" => delay = Duration::from_millis(parse_count("--delay", args.next())?.into()),
+            "--frames" => {
+                frames = Some(PathBuf::from(args.next().ok_or("--frames expects a file")?));
+            }
+            "--no-color" => colour = false,
+            _ => run_args.push(arg.clone()),
+        }
+    }
+
+    Ok(Day15Args {
+        run: parse_run_args(&run_args)?,
+        every,
+        delay,
+        frames,
+        colour,
+    })
+}
+
+/// Plays the frames in the terminal, reading commands from stdin between them.
+fn play(replay: Replay, args: &Day15Args) {
+    let (sender, commands) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines().map_while(Result::ok) {
+            if sender.send(line.trim().to_string()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let total = replay.total_moves();
+    let mut delay = args.delay;
+    let mut paused = false;
+    let mut frames = replay;
+    loop {
+        while let Ok(command) = commands.try_recv() {
+            match command.as_str() {
+                "" | "p" => paused = !paused,
+                "+" => delay = (delay / 2).max(Duration::from_millis(1)),
+                "-" => delay *= 2,
+                "q" => return,
+                _ => {}
+            }
+        }
+        if paused {
+            thread::sleep(Duration::from_millis(50));
+            continue;
+        }
+        let Some(frame) = frames.next() else {
+            return;
+        };
+        let warehouse = if args.colour {
+            replay::colourise(&frame.warehouse)
+        } else {
+            frame.warehouse
+        };
+        print!("\x1b[2J\x1b[H{}\n{warehouse}", frame.title);
+        println!(
+            "Move {}/{total}, GPS sum {}, {delay:?} per frame\n{}",
+            frame.moves, frame.gps_sum, frame.stats
+        );
+        thread::sleep(delay);
+    }
+}
+
+fn replay(args: &Day15Args) -> Result<(), AocError> {
+    let input = args.run.inputs.read(15, args.run.input_name.as_deref())?;
+    let (warehouse, directions) = Day15::read(&input)?;
+    let warehouse = warehouse.for_part(args.run.parts[0]);
+    let frames = Replay::new(warehouse, &directions, args.every);
+    match &args.frames {
+        Some(path) => {
+            let frames: Vec<_> = frames.collect();
+            let count = frames.len();
+            fs::write(path, replay::dump(frames)).map_err(|source| AocError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            println!("Wrote {} frames to {}", count, path.display());
+        }
+        None => play(frames, args),
+    }
+    Ok(())
+}
+
+/// Runs every move, printing what the moves did.
+fn stats(args: &Day15Args) -> Result<(), AocError> {
+    let input = args.run.inputs.read(15, args.run.input_name.as_deref())?;
+    let (warehouse, directions) = Day15::read(&input)?;
+    for &part in &args.run.parts {
+        let mut warehouse = warehouse.for_part(part);
+        let before = Instant::now();
+        let stats = warehouse.run(&directions);
+        println!(
+            "Part {part}: {stats}, GPS sum {} ({:?})",
+            warehouse.gps_sum(),
+            before.elapsed()
+        );
+    }
+    Ok(())
+}
+
+fn day15(args: &[String]) -> ExitCode {
+    let Some((command, rest)) = args.split_first() else {
+        eprintln!("day15 expects a command\n\n{USAGE}");
+        return ExitCode::from(2);
+    };
+    let args = match parse_day15_args(rest) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = match command.as_str() {
+        "replay" => replay(&args),
+        "stats" => stats(&args),
+        _ => {
+            eprintln!("Unknown day15 command: {command}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct Day17Args {
+    run: RunArgs,
+    registers: Vec<(Register, u64)>,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Register>,
+    trace: Option<PathBuf>,
+    output: Option<Vec<u64>>,
+}
+
+fn parse_register(name: &str) -> Result<Register, String> {
+    Register::from_name(name).ok_or(format!("Invalid register: {name}"))
+}
+
+fn parse_day17_args(args: &[String]) -> Result<Day17Args, String> {
+    let mut run_args = vec![String::from("17")];
+    let mut registers = Vec::new();
+    let mut breakpoints = Vec::new();
+    let mut watches = Vec::new();
+    let mut trace = None;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--set" => {
+                let value = args.next().ok_or("--set expects a value")?;
+                let (name, value) = value
+                    .split_once('=')
+                    .ok_or(format!("Invalid value for --set: {value}"))?;
+                let value = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value for --set: {value}"))?;
+                registers.push((parse_register(name)?, value));
+            }
+            "--break" => breakpoints.push(args.next().ok_or("--break expects a value")?.parse()?),
+            "--watch" => watches.push(parse_register(
+                args.next().ok_or("--watch expects a register")?,
+            )?),
+            "--trace" => trace = Some(PathBuf::from(args.next().ok_or("--trace expects a file")?)),
+            "--output" => {
+                let values = args.next().ok_or("--output expects values")?;
+                let values = values
+                    .split(',')
+                    .map(|value| value.trim().parse().ok().filter(|&n| n < 8))
+                    .collect::<Option<_>>()
+                    .ok_or(format!("Invalid value for --output: {values}"))?;
+                output = Some(values);
+            }
+            _ => run_args.push(arg.clone()),
+        }
+    }
+
+    Ok(Day17Args {
+        run: parse_run_args(&run_args)?,
+        registers,
+        breakpoints,
+        watches,
+        trace,
+        output,
+    })
+}
+
+fn debugger(args: &Day17Args) -> Result<Debugger, AocError> {
+    let input = args.run.inputs.read(17, args.run.input_name.as_deref())?;
+    let mut debugger = Debugger::new(Day17::read(&input)?);
+    for &(register, value) in &args.registers {
+        debugger.set(register, value);
+    }
+    for &breakpoint in &args.breakpoints {
+        debugger.add_breakpoint(breakpoint);
+    }
+    for &register in &args.watches {
+        debugger.watch(register);
+    }
+    Ok(debugger)
+}
+
+fn disasm(args: &Day17Args) -> Result<(), AocError> {
+    let debugger = debugger(args)?;
+    let program = &debugger.machine().instructions;
+    print!("{}", instruction::disassemble(program));
+    println!();
+    print!("{}", decompile::decompile(program));
+    Ok(())
+}
+
+/// Reads debugger commands from stdin until `quit` or end of input. An empty line
+/// repeats the last step or continue.
+fn debug(args: &Day17Args) -> Result<(), AocError> {
+    let mut debugger = debugger(args)?;
+    let stdin_error = |source| AocError::Io {
+        path: PathBuf::from("<stdin>"),
+        source,
+    };
+    println!("{}", debugger.status());
+
+    let mut last = Command::Step(1);
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("(day17) ");
+        io::stdout().flush().map_err(stdin_error)?;
+        let Some(line) = lines.next().transpose().map_err(stdin_error)? else {
+            println!();
+            break;
+        };
+        let command = if line.trim().is_empty() {
+            last.clone()
+        } else {
+            match line.parse::<Command>() {
+                Ok(command) => command,
+                Err(e) => {
+                    println!("{e}");
+                    continue;
+                }
+            }
+        };
+        if matches!(command, Command::Step(_) | Command::Continue) {
+            last = command.clone();
+        }
+        if command == Command::Quit {
+            break;
+        }
+        println!("{}", debugger.execute(command));
+    }
+
+    if let Some(path) = &args.trace {
+        debugger.save_trace(path)?;
+        println!(
+            "Wrote {} steps to {}",
+            debugger.trace().len(),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn trace(args: &Day17Args) -> Result<(), AocError> {
+    let mut debugger = debugger(args)?;
+    let stop = debugger.run(STEP_LIMIT);
+    match &args.trace {
+        Some(path) => debugger.save_trace(path)?,
+        None => print!("{}", debugger.trace_text()),
+    }
+    match stop {
+        Stop::Paused => eprintln!("Stopped after {STEP_LIMIT} steps"),
+        Stop::Fault(fault) => eprintln!("Fault: {fault}"),
+        _ => {}
+    }
+    eprintln!("{}", debugger.status());
+    Ok(())
+}
+
+fn asm(path: &str, args: &Day17Args) -> Result<(), AocError> {
+    let path = PathBuf::from(path);
+    let source = if path.as_os_str() == "-" {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(&path)
+    }
+    .map_err(|source| AocError::Io {
+        path: path.clone(),
+        source,
+    })?;
+    let program = assembler::assemble(&source).map_err(|source| AocError::InvalidFile {
+        path: path.clone(),
+        source,
+    })?;
+
+    let mut registers = Registers::default();
+    for &(register, value) in &args.registers {
+        registers.set(register, value);
+    }
+    print!("{}", assembler::to_input(&registers, &program));
+    Ok(())
+}
+
+fn solve(args: &Day17Args) -> Result<(), AocError> {
+    let machine = debugger(args)?.machine().clone();
+    let output = args.output.as_ref().unwrap_or(&machine.instructions);
+    match symbolic::solve(&machine, output) {
+        Some(a) => println!("{a}"),
+        None => println!("No value of A outputs {output:?}"),
+    }
+    Ok(())
+}
+
+fn day17(args: &[String]) -> ExitCode {
+    let Some((command, mut rest)) = args.split_first() else {
+        eprintln!("day17 expects a command\n\n{USAGE}");
+        return ExitCode::from(2);
+    };
+    let mut source = None;
+    if command == "asm" {
+        let Some((path, options)) = rest.split_first() else {
+            eprintln!("asm expects a source file\n\n{USAGE}");
+            return ExitCode::from(2);
+        };
+        (source, rest) = (Some(path), options);
+    }
+    let args = match parse_day17_args(rest) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    let result = match command.as_str() {
+        "asm" => asm(source.unwrap(), &args),
+        "disasm" => disasm(&args),
+        "trace" => trace(&args),
+        "solve" => solve(&args),
+        "debug" if *args.run.inputs.source() == InputSource::Stdin => {
+            eprintln!("error: debug reads commands from stdin, so the input must come from a file");
+            return ExitCode::from(2);
+        }
+        "debug" => debug(&args),
+        _ => {
+            eprintln!("Unknown day17 command: {command}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("day9") => day9(&args[1..]),
+        Some("day15") => day15(&args[1..]),
+        Some("day17") => day17(&args[1..]),
+        _ => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
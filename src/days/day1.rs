@@ -0,0 +1,71 @@
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    lines(input)
+        .map(|line| {
+            let (left, right) = line.split_once("   ")?;
+            Ok((line.parse::<i32>(left)?, line.parse::<i32>(right)?))
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Parsed = (Vec<i32>, Vec<i32>);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((left, right): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        left.sort();
+        right.sort();
+
+        Ok(left
+            .iter()
+            .zip(right.iter())
+            .map(|(a, b)| (*a - *b).abs())
+            .sum::<i32>()
+            .to_string())
+    }
+
+    fn part2((left, right): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(left
+            .iter()
+            .map(|lval| *lval * (right.iter().filter(|rval| **rval == *lval).count() as i32))
+            .sum::<i32>()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        assert_eq!("11", Day1::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        assert_eq!("31", Day1::solve_part2(input).unwrap());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{Answer, Grid, ParseError, Point, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_with(input, |b| (b as char).to_digit(10))
+}
+
+fn trailheads(input: &Grid<u32>, pos: Point, cache: &mut Grid<i32>) -> u32 {
+    let mut ans = 0;
+    if input[pos] == 9 {
+        ans = 1;
+    } else if cache[pos] != -1 {
+        return cache[pos] as u32;
+    } else {
+        for next in input.neighbours4(pos) {
+            if input[next] == input[pos] + 1 {
+                ans += trailheads(input, next, cache)
+            }
+        }
+    }
+    cache[pos] = ans as i32;
+    ans
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    // TC for part1 can be improved with HashSet by a factor of N, but I'm simply re-using the code from part2
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0;
+        for start in input.points().filter(|&p| input[p] == 0) {
+            let mut cache = Grid::filled(input.width(), input.height(), -1);
+            trailheads(input, start, &mut cache);
+
+            for p in input.points() {
+                if input[p] == 9 && cache[p] > 0 {
+                    ans += 1;
+                }
+            }
+        }
+        Ok(ans.to_string())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0;
+        for start in input.points().filter(|&p| input[p] == 0) {
+            let mut cache = Grid::filled(input.width(), input.height(), -1);
+            let val = trailheads(input, start, &mut cache);
+            ans += val;
+        }
+        Ok(ans.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("36", Day10::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("81", Day10::solve_part2(INPUT).unwrap());
+    }
+}
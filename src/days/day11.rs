@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    lines(input)
+        .flat_map(|line| {
+            line.text
+                .split_ascii_whitespace()
+                .map(move |n| line.parse(n))
+        })
+        .collect()
+}
+
+fn count_digits(num: u64) -> u32 {
+    let mut count = 0;
+    let mut n = num;
+    while n > 0 {
+        count += 1;
+        n /= 10;
+    }
+    count
+}
+fn split_num_middle(num: u64) -> (u64, u64) {
+    let count = count_digits(num);
+    let mid = count / 2;
+    let left = num / 10u64.pow(mid);
+    let right = num % 10u64.pow(mid);
+
+    (left, right)
+}
+
+fn count_stones(num: u64, rem: u32, cache: &mut HashMap<(u64, u32), u64>) -> u64 {
+    let ans;
+    if let Some(&val) = cache.get(&(num, rem)) {
+        return val;
+    }
+    if rem == 0 {
+        ans = 1;
+    } else if num == 0 {
+        ans = count_stones(1, rem - 1, cache);
+    } else if count_digits(num).is_multiple_of(2) {
+        let (left, right) = split_num_middle(num);
+        ans = count_stones(left, rem - 1, cache) + count_stones(right, rem - 1, cache);
+    } else {
+        ans = count_stones(num * 2024, rem - 1, cache);
+    }
+    cache.insert((num, rem), ans);
+    ans
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut cache: HashMap<(u64, u32), u64> = HashMap::new();
+        Ok(input
+            .iter()
+            .map(|&num| count_stones(num, 25, &mut cache))
+            .sum::<u64>()
+            .to_string())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut cache: HashMap<(u64, u32), u64> = HashMap::new();
+        Ok(input
+            .iter()
+            .map(|&num| count_stones(num, 75, &mut cache))
+            .sum::<u64>()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "125 17";
+        assert_eq!("55312", Day11::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() {
+        let input = "125 17";
+
+        assert_eq!("", Day11::solve_part2(input).unwrap());
+    }
+}
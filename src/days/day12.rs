@@ -0,0 +1,133 @@
+use crate::{Answer, Direction, Grid, ParseError, Point, Solution, Unsolvable, Vector};
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
+}
+
+fn traverse_1(input: &Grid<u8>, pos: Point, visited: &mut Grid<bool>) -> (u64, u64) {
+    visited[pos] = true;
+    let mut area = 1;
+    let mut per = 0;
+    for d in Direction::ALL {
+        match input.step(pos, d) {
+            Some(next) if input[next] == input[pos] => {
+                if !visited[next] {
+                    let (narea, np) = traverse_1(input, next, visited);
+                    area += narea;
+                    per += np;
+                }
+            }
+            _ => per += 1,
+        }
+    }
+    (area, per)
+}
+
+fn traverse_2(input: &Grid<u8>, pos: Point, visited: &mut Grid<bool>) -> (u64, u64) {
+    let matches = |d: Vector| -> bool {
+        input
+            .offset(pos, d)
+            .is_some_and(|next| input[next] == input[pos])
+    };
+
+    visited[pos] = true;
+
+    let mut area = 1;
+    let mut sides = 0;
+
+    for d in Direction::ALL {
+        let d1 = d.vector();
+        let d2 = d.turn_right().vector();
+
+        if !matches(d1) && !matches(d2) {
+            sides += 1;
+        }
+        if matches(d1) && matches(d2) && !matches(d1 + d2) {
+            sides += 1;
+        }
+
+        if let Some(next) = input.offset(pos, d1) {
+            if matches(d1) && !visited[next] {
+                let (narea, nsides) = traverse_2(input, next, visited);
+                area += narea;
+                sides += nsides;
+            }
+        }
+    }
+    (area, sides)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0u64;
+        let mut visited = Grid::filled(input.width(), input.height(), false);
+
+        for p in input.points() {
+            if !visited[p] {
+                let (area, perimeter) = traverse_1(input, p, &mut visited);
+                ans += area * perimeter;
+            }
+        }
+        Ok(ans.to_string())
+    }
+
+    // "Inspired" by errichto's solution. Count convex and concave corners.
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0u64;
+        let mut visited = Grid::filled(input.width(), input.height(), false);
+
+        for p in input.points() {
+            if !visited[p] {
+                let (area, sides) = traverse_2(input, p, &mut visited);
+                ans += area * sides;
+            }
+        }
+        Ok(ans.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+        assert_eq!("1930", Day12::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+        assert_eq!("1206", Day12::solve_part2(input).unwrap());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    parse::{sections, Line},
+    Answer, ParseError, Solution, Unsolvable,
+};
+use regex::Regex;
+
+#[derive(Debug, Clone, Copy)]
+struct Coord {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug)]
+pub struct ClawMachine {
+    a: Coord,
+    b: Coord,
+    p: Coord,
+}
+
+fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let button = Regex::new("Button [A,B]: X\\+([0-9]+), Y\\+([0-9]+)").unwrap();
+    let prize = Regex::new("Prize: X=([0-9]+), Y=([0-9]+)").unwrap();
+    let parse_coord = |re: &Regex, line: &Line| {
+        let caps = re
+            .captures(line.text)
+            .ok_or_else(|| line.error(format!("expected `{}`", re.as_str())))?;
+        Ok(Coord {
+            x: line.parse(&caps[1])?,
+            y: line.parse(&caps[2])?,
+        })
+    };
+
+    sections(input)
+        .iter()
+        .map(|cm| match cm.as_slice() {
+            [a, b, p] => Ok(ClawMachine {
+                a: parse_coord(&button, a)?,
+                b: parse_coord(&button, b)?,
+                p: parse_coord(&prize, p)?,
+            }),
+            _ => Err(cm[0].error("expected two buttons and a prize")),
+        })
+        .collect()
+}
+
+// Find intersection of straight lines formed by
+// alpha * A_vector + beta * B_vector = Prize_vector
+
+fn solve_machine(m: &ClawMachine) -> Result<i64, Unsolvable> {
+    let det = m.a.x * m.b.y - m.a.y * m.b.x;
+    if det == 0 {
+        return Err(Unsolvable::new(format!(
+            "the buttons for the prize at X={}, Y={} move the claw in the same direction",
+            m.p.x, m.p.y
+        )));
+    }
+    let beta = (m.a.x * m.p.y - m.a.y * m.p.x) / det;
+    // A can't move along only one axis, or the buttons would be collinear
+    let alpha = if m.a.x != 0 {
+        (m.p.x - m.b.x * beta) / m.a.x
+    } else {
+        (m.p.y - m.b.y * beta) / m.a.y
+    };
+
+    // Non-integer solutions will fail
+    if m.a.x * alpha + m.b.x * beta != m.p.x || m.a.y * alpha + m.b.y * beta != m.p.y {
+        return Ok(0);
+    }
+    Ok(3 * alpha + beta)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed = Vec<ClawMachine>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(input
+            .iter()
+            .map(solve_machine)
+            .sum::<Result<i64, _>>()?
+            .to_string())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(input
+            .iter()
+            .map(|m| ClawMachine {
+                p: Coord {
+                    x: m.p.x + 10_000_000_000_000,
+                    y: m.p.y + 10_000_000_000_000,
+                },
+                ..*m
+            })
+            .map(|m| solve_machine(&m))
+            .sum::<Result<i64, _>>()?
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+    #[test]
+    fn test_part1() {
+        assert_eq!("480", Day13::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_degenerate_buttons() {
+        let zero_x = "Button A: X+0, Y+3
+Button B: X+2, Y+1
+Prize: X=4, Y=8";
+        assert_eq!("8", Day13::solve_part1(zero_x).unwrap());
+
+        let collinear = "Button A: X+2, Y+4
+Button B: X+1, Y+2
+Prize: X=5, Y=10";
+        let error = Day13::solve_part1(collinear).unwrap_err().to_string();
+        assert!(error.contains("same direction"));
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() {
+        assert_eq!("", Day13::solve_part2(INPUT).unwrap());
+    }
+}
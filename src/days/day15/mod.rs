@@ -0,0 +1,508 @@
+pub mod replay;
+
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use crate::{
+    parse::{expect_sections, Line},
+    Answer, Direction, Grid, ParseError, Point, Solution, Unsolvable,
+};
+
+type Parsed = (Warehouse, Vec<Direction>);
+
+/// A box's left edge and how many cells wide it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoxSpan {
+    pub left: Point,
+    pub width: usize,
+}
+
+impl BoxSpan {
+    fn cells(self) -> impl Iterator<Item = Point> {
+        let BoxSpan { left, width } = self;
+        (left.col..left.col + width).map(move |col| Point::new(left.row, col))
+    }
+}
+
+/// What one move did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// The robot moved, pushing this many boxes.
+    Moved(usize),
+    /// A wall stopped the robot or one of the boxes it pushed.
+    Blocked,
+}
+
+/// Totals over a run of moves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub moves: usize,
+    pub blocked: usize,
+    pub boxes_moved: usize,
+    /// The most boxes moved at once.
+    pub largest_push: usize,
+}
+
+impl Stats {
+    pub fn record(&mut self, outcome: Outcome) {
+        self.moves += 1;
+        match outcome {
+            Outcome::Moved(boxes) => {
+                self.boxes_moved += boxes;
+                self.largest_push = self.largest_push.max(boxes);
+            }
+            Outcome::Blocked => self.blocked += 1,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} moves, {} blocked, {} boxes moved, at most {} at once",
+            self.moves, self.blocked, self.boxes_moved, self.largest_push
+        )
+    }
+}
+
+/// The warehouse between moves. Boxes are numbered, and every cell a box covers
+/// holds its number, so boxes of any width can be pushed as one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+    walls: Grid<bool>,
+    cells: Grid<Option<usize>>,
+    boxes: Vec<BoxSpan>,
+    pub robot: Point,
+}
+
+/// Reads the boxes in a row: `O` is one cell wide, and wider boxes are a `[`, any
+/// number of `-` and a `]`, so `[]` is part 2's box and `[-]` one three cells wide.
+fn parse_boxes(line: &Line, row: usize) -> Result<Vec<BoxSpan>, ParseError> {
+    let mut boxes = Vec::new();
+    let mut open = None;
+    for (col, ch) in line.text.trim().bytes().enumerate() {
+        match (ch, open) {
+            (b'O', None) => boxes.push(BoxSpan {
+                left: Point::new(row, col),
+                width: 1,
+            }),
+            (b'[', None) => open = Some(col),
+            (b'-', Some(_)) => {}
+            (b']', Some(left)) => {
+                boxes.push(BoxSpan {
+                    left: Point::new(row, left),
+                    width: col + 1 - left,
+                });
+                open = None;
+            }
+            (b'-' | b']', None) => {
+                return Err(line.error(format!("`{}` outside a box", ch as char)))
+            }
+            (_, Some(left)) => {
+                return Err(line.error(format!("the box at column {} isn't closed", left + 1)))
+            }
+            _ => {}
+        }
+    }
+    match open {
+        Some(left) => Err(line.error(format!("the box at column {} isn't closed", left + 1))),
+        None => Ok(boxes),
+    }
+}
+
+fn parse_warehouse(rows: &[Line]) -> Result<Warehouse, ParseError> {
+    let grid = Grid::from_lines(rows, |b| b"#.O@[-]".contains(&b).then_some(b))?;
+    let robots: Vec<Point> = grid
+        .iter()
+        .filter(|(_, &ch)| ch == b'@')
+        .map(|(p, _)| p)
+        .collect();
+    let [robot] = robots[..] else {
+        return Err(ParseError::new("expected exactly one robot `@`"));
+    };
+    let (width, height) = (grid.width(), grid.height());
+    let on_edge = |p: Point| p.row == 0 || p.col == 0 || p.row == height - 1 || p.col == width - 1;
+    if grid.iter().any(|(p, &ch)| on_edge(p) && ch != b'#') {
+        return Err(ParseError::new("the warehouse must be walled in"));
+    }
+
+    let mut boxes = Vec::new();
+    for (row, line) in rows.iter().enumerate() {
+        boxes.extend(parse_boxes(line, row)?);
+    }
+    Ok(Warehouse::new(grid.map(|&ch| ch == b'#'), boxes, robot))
+}
+
+fn parse_input(input: &str) -> Result<Parsed, ParseError> {
+    let [warehouse, moves] = expect_sections(input, ["warehouse", "moves"])?;
+    let warehouse = parse_warehouse(&warehouse)?;
+    let directions = moves
+        .iter()
+        .flat_map(|line| {
+            line.text.trim().chars().map(move |ch| {
+                Direction::from_char(ch).ok_or_else(|| line.error(format!("invalid move `{ch}`")))
+            })
+        })
+        .collect::<Result<Vec<Direction>, ParseError>>()?;
+    Ok((warehouse, directions))
+}
+
+/// The warehouse is walled in, so moving from any non-wall cell stays inside it.
+fn step(p: Point, dir: Direction) -> Point {
+    p.checked_add(dir.vector())
+        .expect("the warehouse is walled in")
+}
+
+impl Warehouse {
+    pub fn new(walls: Grid<bool>, boxes: Vec<BoxSpan>, robot: Point) -> Self {
+        let mut cells = Grid::filled(walls.width(), walls.height(), None);
+        for (id, &span) in boxes.iter().enumerate() {
+            for p in span.cells() {
+                cells[p] = Some(id);
+            }
+        }
+        Warehouse {
+            walls,
+            cells,
+            boxes,
+            robot,
+        }
+    }
+
+    /// Everything `factor` times as wide, boxes included, but the robot. Part 2 is
+    /// the warehouse scaled by 2.
+    pub fn scaled(&self, factor: usize) -> Self {
+        let cells = self
+            .walls
+            .rows()
+            .flatten()
+            .flat_map(|&wall| std::iter::repeat_n(wall, factor))
+            .collect();
+        let walls = Grid::from_cells(self.walls.width() * factor, cells);
+        let scale = |p: Point| Point::new(p.row, p.col * factor);
+        let boxes = (self.boxes.iter())
+            .map(|span| BoxSpan {
+                left: scale(span.left),
+                width: span.width * factor,
+            })
+            .collect();
+        Warehouse::new(walls, boxes, scale(self.robot))
+    }
+
+    pub fn for_part(&self, part: u8) -> Self {
+        match part {
+            1 => self.clone(),
+            _ => self.scaled(2),
+        }
+    }
+
+    pub fn boxes(&self) -> &[BoxSpan] {
+        &self.boxes
+    }
+
+    /// The cells the box `id` moves into when pushed towards `d`, which are a row of
+    /// cells just like a box.
+    fn front(&self, id: usize, d: Direction) -> BoxSpan {
+        let span = self.boxes[id];
+        match d {
+            Direction::Left => BoxSpan {
+                left: step(span.left, d),
+                width: 1,
+            },
+            Direction::Right => BoxSpan {
+                left: Point::new(span.left.row, span.left.col + span.width),
+                width: 1,
+            },
+            _ => BoxSpan {
+                left: step(span.left, d),
+                ..span
+            },
+        }
+    }
+
+    /// Every box that moves when `first` is pushed towards `d`, found breadth first
+    /// across the push front so each is visited once however the stack branches and
+    /// rejoins. `None` if any of them would hit a wall.
+    fn pushed_boxes(&self, first: usize, d: Direction) -> Option<Vec<usize>> {
+        let mut boxes = vec![first];
+        let mut seen = HashSet::from([first]);
+        let mut i = 0;
+        while let Some(&id) = boxes.get(i) {
+            for p in self.front(id, d).cells() {
+                if self.walls[p] {
+                    return None;
+                }
+                if let Some(other) = self.cells[p] {
+                    if seen.insert(other) {
+                        boxes.push(other);
+                    }
+                }
+            }
+            i += 1;
+        }
+        Some(boxes)
+    }
+
+    /// Moves the robot one step, pushing the boxes in the way if none of them hit a
+    /// wall. The boxes are all lifted off the grid before any is put down again.
+    pub fn push(&mut self, d: Direction) -> Outcome {
+        let np = step(self.robot, d);
+        if self.walls[np] {
+            return Outcome::Blocked;
+        }
+        let boxes = match self.cells[np] {
+            Some(first) => match self.pushed_boxes(first, d) {
+                Some(boxes) => boxes,
+                None => return Outcome::Blocked,
+            },
+            None => Vec::new(),
+        };
+        for &id in &boxes {
+            for p in self.boxes[id].cells() {
+                self.cells[p] = None;
+            }
+        }
+        for &id in &boxes {
+            self.boxes[id].left = step(self.boxes[id].left, d);
+            for p in self.boxes[id].cells() {
+                self.cells[p] = Some(id);
+            }
+        }
+        self.robot = np;
+        Outcome::Moved(boxes.len())
+    }
+
+    /// Makes all the moves, returning what they did.
+    pub fn run(&mut self, directions: &[Direction]) -> Stats {
+        let mut stats = Stats::default();
+        for &d in directions {
+            stats.record(self.push(d));
+        }
+        stats
+    }
+
+    /// The sum of the boxes' GPS coordinates, measured to their left edge.
+    pub fn gps_sum(&self) -> usize {
+        (self.boxes.iter())
+            .map(|span| 100 * span.left.row + span.left.col)
+            .sum()
+    }
+}
+
+/// The map as the puzzle draws it, robot included.
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.walls.height() {
+            let line: String = (0..self.walls.width())
+                .map(|col| {
+                    let p = Point::new(row, col);
+                    if p == self.robot {
+                        return '@';
+                    } else if self.walls[p] {
+                        return '#';
+                    }
+                    let Some(id) = self.cells[p] else {
+                        return '.';
+                    };
+                    match self.boxes[id] {
+                        BoxSpan { width: 1, .. } => 'O',
+                        BoxSpan { left, .. } if left == p => '[',
+                        BoxSpan { left, width } if left.col + width - 1 == col => ']',
+                        _ => '-',
+                    }
+                })
+                .collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+fn simulate(mut warehouse: Warehouse, directions: &[Direction]) -> usize {
+    warehouse.run(directions);
+    warehouse.gps_sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed = Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    // Simulate as-is. Boxes are one cell wide here, the pusher handles any width.
+    fn part1((warehouse, directions): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(simulate(warehouse.clone(), directions).to_string())
+    }
+
+    // The same, with everything twice as wide. BFS finds connected boxes to move (if possible).
+    fn part2((warehouse, directions): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(simulate(warehouse.scaled(2), directions).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_1() {
+        let input = "########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<";
+
+        assert_eq!("2028", Day15::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part1_2() {
+        let input = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+        assert_eq!("10092", Day15::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+        assert_eq!("9021", Day15::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_box_widths() {
+        let input = "#########
+#.......#
+#.[-]O..#
+#..[]...#
+#...@...#
+#########
+
+^^>^";
+        let (warehouse, directions) = Day15::parse(input).unwrap();
+        let widths: Vec<usize> = warehouse.boxes().iter().map(|span| span.width).collect();
+        assert_eq!(vec![3, 1, 2], widths);
+        assert_eq!(
+            input.split("\n\n").next().unwrap(),
+            warehouse.to_string().trim_end()
+        );
+
+        let mut pushed = warehouse.clone();
+        for &d in &directions {
+            pushed.push(d);
+        }
+        assert_eq!(
+            "#########
+#.[-]O..#
+#..[]@..#
+#.......#
+#.......#
+#########
+",
+            pushed.to_string()
+        );
+        assert_eq!(410, pushed.gps_sum());
+        assert!(warehouse
+            .scaled(3)
+            .to_string()
+            .contains("###...[-------][-]......###"));
+
+        let error = |map: &str| {
+            Day15::parse(&format!("{map}\n\n^"))
+                .unwrap_err()
+                .to_string()
+        };
+        assert!(error("#####\n#@[.#\n#####").contains("isn't closed"));
+        assert!(error("#####\n#@-.#\n#####").contains("outside a box"));
+        assert!(error("#####\n#@...\n#####").contains("walled in"));
+    }
+
+    #[test]
+    fn test_push_stats() {
+        let input = "##########
+#........#
+#...[]...#
+#..[][]..#
+#...[]...#
+#....@...#
+##########
+
+^^<";
+        let (mut warehouse, directions) = Day15::parse(input).unwrap();
+        // The top box is reached from both boxes below it, but only moves once.
+        assert_eq!(Outcome::Moved(4), warehouse.push(directions[0]));
+        assert_eq!(Outcome::Blocked, warehouse.push(directions[1]));
+        assert_eq!(
+            "##########
+#...[]...#
+#..[][]..#
+#...[]...#
+#....@...#
+#........#
+##########
+",
+            warehouse.to_string()
+        );
+
+        let stats = Day15::parse(input).unwrap().0.run(&directions);
+        let expected = Stats {
+            moves: 3,
+            blocked: 1,
+            boxes_moved: 4,
+            largest_push: 4,
+        };
+        assert_eq!(expected, stats);
+    }
+}
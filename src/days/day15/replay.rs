@@ -0,0 +1,172 @@
+use super::{Stats, Warehouse};
+use crate::Direction;
+
+/// The warehouse as drawn after some number of moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// `Initial state:` or `Move <:`, as in the puzzle's worked examples.
+    pub title: String,
+    pub moves: usize,
+    pub gps_sum: usize,
+    /// What the moves so far did.
+    pub stats: Stats,
+    pub warehouse: String,
+}
+
+/// Replays the moves, yielding the initial state and then a frame every `every`
+/// moves, with the final state always last.
+pub struct Replay<'a> {
+    warehouse: Warehouse,
+    directions: &'a [Direction],
+    every: usize,
+    moves: usize,
+    stats: Stats,
+    started: bool,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(warehouse: Warehouse, directions: &'a [Direction], every: usize) -> Self {
+        Replay {
+            warehouse,
+            directions,
+            every: every.max(1),
+            moves: 0,
+            stats: Stats::default(),
+            started: false,
+        }
+    }
+
+    pub fn total_moves(&self) -> usize {
+        self.directions.len()
+    }
+
+    fn frame(&self, title: String) -> Frame {
+        Frame {
+            title,
+            moves: self.moves,
+            gps_sum: self.warehouse.gps_sum(),
+            stats: self.stats,
+            warehouse: self.warehouse.to_string(),
+        }
+    }
+}
+
+impl Iterator for Replay<'_> {
+    type Item = Frame;
+
+    fn next(&mut self) -> Option<Frame> {
+        if !self.started {
+            self.started = true;
+            return Some(self.frame(String::from("Initial state:")));
+        }
+        let end = self.directions.len().min(self.moves + self.every);
+        let last = *self.directions[self.moves..end].last()?;
+        for &d in &self.directions[self.moves..end] {
+            self.stats.record(self.warehouse.push(d));
+        }
+        self.moves = end;
+        let title = match self.every {
+            1 => format!("Move {}:", last.to_char()),
+            _ => format!("Move {} ({}):", self.moves, last.to_char()),
+        };
+        Some(self.frame(title))
+    }
+}
+
+/// Frames one after another, separated by blank lines like the puzzle's examples.
+pub fn dump(frames: impl IntoIterator<Item = Frame>) -> String {
+    let frames: Vec<String> = frames
+        .into_iter()
+        .map(|frame| format!("{}\n{}", frame.title, frame.warehouse))
+        .collect();
+    frames.join("\n")
+}
+
+/// Colours a drawn warehouse for the terminal: grey walls, yellow boxes and a bold
+/// red robot.
+pub fn colourise(warehouse: &str) -> String {
+    let mut coloured = String::with_capacity(warehouse.len() * 2);
+    let mut current = "";
+    for ch in warehouse.chars() {
+        let colour = match ch {
+            '#' => "\x1b[90m",
+            'O' | '[' | '-' | ']' => "\x1b[33m",
+            '@' => "\x1b[1;31m",
+            _ => "\x1b[0m",
+        };
+        if colour != current {
+            coloured.push_str(colour);
+            current = colour;
+        }
+        coloured.push(ch);
+    }
+    coloured.push_str("\x1b[0m");
+    coloured
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day15::Day15, Solution};
+
+    #[test]
+    fn test_replay() {
+        let input = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+        let (warehouse, directions) = Day15::parse(input).unwrap();
+        let frames: Vec<Frame> = Replay::new(warehouse.scaled(2), &directions, 1).collect();
+        assert_eq!(12, frames.len());
+        let text = dump(frames.clone());
+        // The first and last frames of the puzzle's smaller part 2 example.
+        assert!(text.starts_with(
+            "Initial state:
+##############
+##......##..##
+##..........##
+##....[][]@.##
+##....[]....##
+##..........##
+##############
+
+Move <:
+"
+        ));
+        assert!(text.ends_with(
+            "Move ^:
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+"
+        ));
+
+        let every_four: Vec<Frame> = Replay::new(warehouse.scaled(2), &directions, 4).collect();
+        assert_eq!(
+            vec![
+                "Initial state:",
+                "Move 4 (<):",
+                "Move 8 (<):",
+                "Move 11 (^):"
+            ],
+            every_four
+                .iter()
+                .map(|f| f.title.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(frames[11].warehouse, every_four[3].warehouse);
+        assert_eq!(
+            "\x1b[90m##\x1b[1;31m@\x1b[0m.\x1b[33m[][-]\x1b[0m",
+            colourise("##@.[][-]")
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+
+use crate::{
+    search::{self, Paths},
+    Answer, Direction, Grid, ParseError, Point, Solution, Unsolvable,
+};
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::parse_with(input, |b| b"#.SE".contains(&b).then_some(b))?;
+    for (marker, name) in [(b'S', "start"), (b'E', "end")] {
+        if grid.find(&marker).is_none() {
+            return Err(ParseError::new(format!(
+                "cannot find {name} tile `{}`",
+                marker as char
+            )));
+        }
+    }
+    Ok(grid)
+}
+
+type State = (Point, Direction);
+
+/// Moving forward costs 1 and turning in place costs 1000. The edge of the grid counts
+/// as a wall, so mazes that aren't walled in work too.
+fn moves(grid: &Grid<u8>, &(p, dir): &State) -> Vec<(State, usize)> {
+    let mut moves = vec![((p, dir.turn_left()), 1000), ((p, dir.turn_right()), 1000)];
+    if let Some(next) = grid.step(p, dir).filter(|&next| grid[next] != b'#') {
+        moves.push(((next, dir), 1));
+    }
+    moves
+}
+
+fn best_paths(grid: &Grid<u8>) -> Result<(usize, Paths<State, usize>), Unsolvable> {
+    let start = (grid.find(&b'S').unwrap(), Direction::Right);
+    let paths = search::dijkstra_all(start, |state| moves(grid, state));
+    let end = grid.find(&b'E').unwrap();
+    let min_cost = Direction::ALL
+        .into_iter()
+        .filter_map(|dir| paths.cost(&(end, dir)))
+        .min()
+        .ok_or_else(|| Unsolvable::new("no path from start to end"))?;
+    Ok((min_cost, paths))
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let start = (grid.find(&b'S').unwrap(), Direction::Right);
+        search::dijkstra(start, |state| moves(grid, state), |&(p, _)| grid[p] == b'E')
+            .map(|(_, cost)| cost.to_string())
+            .ok_or_else(|| Unsolvable::new("no path from start to end"))
+    }
+
+    // All optimal paths end facing whichever directions reach `E` at the minimum cost.
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let (min_cost, paths) = best_paths(grid)?;
+        let end = grid.find(&b'E').unwrap();
+        let ends = Direction::ALL
+            .into_iter()
+            .map(|dir| (end, dir))
+            .filter(|state| paths.cost(state) == Some(min_cost));
+        let tiles: HashSet<Point> = paths
+            .nodes_on_paths(ends)
+            .into_iter()
+            .map(|(p, _)| p)
+            .collect();
+        Ok(tiles.len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1_0() {
+        let input = "############
+#..........#
+#.########.#
+#.#......#.#
+#.#......#.#
+#.#......#.#
+#.#......#.#
+#.#......#.#
+#.#....###.#
+#.#.####...#
+#.##...#.#E#
+#S...#...###
+############";
+        assert_eq!("3028", Day16::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part1_1() {
+        let input = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+        assert_eq!("7036", Day16::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part1_2() {
+        let input = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+        assert_eq!("11048", Day16::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2_1() {
+        let input = "###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+        assert_eq!("45", Day16::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2_2() {
+        let input = "#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+        assert_eq!("64", Day16::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_unwalled() {
+        assert_eq!("2", Day16::solve_part1("S.E").unwrap());
+        assert_eq!("3", Day16::solve_part2("S.E").unwrap());
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use super::{
+    instruction::{Instruction, Register},
+    Registers,
+};
+use crate::{
+    parse::{lines, Line},
+    ParseError,
+};
+
+/// An operand before labels are resolved.
+#[derive(Debug, Clone, Copy)]
+enum Value<'a> {
+    Number(u64),
+    Label(&'a str),
+}
+
+/// What one source line assembles to, along with the line for error messages.
+struct Statement<'a> {
+    line: Line<'a>,
+    values: Vec<Value<'a>>,
+}
+
+fn is_label(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+fn three_bit(line: &Line, operand: &str) -> Result<u64, ParseError> {
+    match line.parse::<u64>(operand)? {
+        n @ 0..=7 => Ok(n),
+        n => Err(line.error(format!("`{n}` is not a 3-bit number"))),
+    }
+}
+
+/// `0`-`3` or a register name. `4`-`6` are accepted as the raw encodings of A-C.
+fn combo(line: &Line, operand: &str) -> Result<u64, ParseError> {
+    if let Some(register) = Register::from_name(operand) {
+        return Ok(4 + register.index() as u64);
+    }
+    match three_bit(line, operand)? {
+        7 => Err(line.error("combo operand 7 is reserved")),
+        n => Ok(n),
+    }
+}
+
+fn instruction<'a>(line: &Line<'a>, text: &'a str) -> Result<Vec<Value<'a>>, ParseError> {
+    let (mnemonic, operands) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let mut operands: Vec<&str> = operands
+        .split(',')
+        .map(str::trim)
+        .filter(|operand| !operand.is_empty())
+        .collect();
+
+    if mnemonic == "raw" {
+        return operands
+            .iter()
+            .map(|operand| Ok(Value::Number(three_bit(line, operand)?)))
+            .collect();
+    }
+    let opcode = Instruction::MNEMONICS
+        .iter()
+        .position(|&m| m == mnemonic)
+        .ok_or_else(|| line.error(format!("unknown instruction `{mnemonic}`")))?;
+
+    // `adv A, 3` as printed by the disassembler, the register being the destination.
+    let destination = match mnemonic {
+        "adv" => Some("A"),
+        "bdv" => Some("B"),
+        "cdv" => Some("C"),
+        _ => None,
+    };
+    if operands.len() == 2 && destination.is_some_and(|d| operands[0].eq_ignore_ascii_case(d)) {
+        operands.remove(0);
+    }
+
+    let operand = match (mnemonic, operands.as_slice()) {
+        ("bxc", []) => Value::Number(0),
+        ("bxc" | "bxl", [operand]) => Value::Number(three_bit(line, operand)?),
+        ("jnz", [operand]) if is_label(operand) => Value::Label(operand),
+        ("jnz", [operand]) => Value::Number(three_bit(line, operand)?),
+        (_, [operand]) => Value::Number(combo(line, operand)?),
+        _ => return Err(line.error(format!("`{mnemonic}` takes one operand"))),
+    };
+    Ok(vec![Value::Number(opcode as u64), operand])
+}
+
+/// Assembles one instruction per line, e.g. `bst A`, `bxl 5`, `cdv B` or `jnz loop`,
+/// into the program's 3-bit values.
+///
+/// Lines may start with `name:` labels, which `jnz` can jump to as long as they are
+/// within its reach of addresses 0-7. `raw 1, 2` emits values as they are, and
+/// everything after `#` or `;` is a comment. Combo operands are `0`-`3` or a register,
+/// `adv`, `bdv` and `cdv` also accept the disassembler's `adv A, 3` form.
+pub fn assemble(source: &str) -> Result<Vec<u64>, ParseError> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+    let mut address = 0;
+    for line in lines(source) {
+        let mut text = line.text.split(['#', ';']).next().unwrap().trim();
+        while let Some((label, rest)) = text.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(line.error(format!("invalid label `{label}`")));
+            }
+            if labels.insert(label, address).is_some() {
+                return Err(line.error(format!("duplicate label `{label}`")));
+            }
+            text = rest.trim();
+        }
+        if text.is_empty() {
+            continue;
+        }
+        let values = instruction(&line, text)?;
+        address += values.len();
+        statements.push(Statement { line, values });
+    }
+
+    let mut program = Vec::with_capacity(address);
+    for Statement { line, values } in statements {
+        for value in values {
+            program.push(match value {
+                Value::Number(n) => n,
+                Value::Label(label) => match labels.get(label) {
+                    Some(&address) if address < 8 => address as u64,
+                    Some(address) => {
+                        return Err(line.error(format!(
+                            "label `{label}` is at address {address}, but jnz can only reach 0-7"
+                        )))
+                    }
+                    None => return Err(line.error(format!("unknown label `{label}`"))),
+                },
+            });
+        }
+    }
+    validate(&program)?;
+    Ok(program)
+}
+
+/// Checks that every opcode has an operand and that no combo operand is the reserved 7,
+/// which `raw` values could otherwise sneak in.
+pub fn validate(program: &[u64]) -> Result<(), ParseError> {
+    if !program.len().is_multiple_of(2) {
+        return Err(ParseError::new(format!(
+            "odd program length {}: the opcode at address {} has no operand",
+            program.len(),
+            program.len() - 1
+        )));
+    }
+    for (address, pair) in program.chunks_exact(2).enumerate() {
+        if let Some(value) = pair.iter().find(|&&value| value > 7) {
+            return Err(ParseError::new(format!("`{value}` is not a 3-bit number")));
+        }
+        let takes_combo = !matches!(pair[0], 1 | 3 | 4);
+        if takes_combo && pair[1] == 7 {
+            return Err(ParseError::new(format!(
+                "`{}` at address {} uses the reserved combo operand 7",
+                Instruction::MNEMONICS[pair[0] as usize],
+                2 * address
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The program as a complete puzzle input, ready to be saved as `inputs/day17.NAME.txt`.
+pub fn to_input(registers: &Registers, program: &[u64]) -> String {
+    let program: Vec<String> = program.iter().map(u64::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        registers.a,
+        registers.b,
+        registers.c,
+        program.join(",")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days::day17::{instruction::disassemble, Day17},
+        Solution,
+    };
+
+    #[test]
+    fn test_assemble() {
+        let source = "
+            # prints A in octal, lowest digit first
+            loop:   out A
+                    adv 3       ; next digit
+                    jnz loop
+        ";
+        let program = assemble(source).unwrap();
+        assert_eq!(vec![5, 4, 0, 3, 3, 0], program);
+
+        let mut registers = Registers::default();
+        registers.set(Register::A, 0o1234);
+        let input = to_input(&registers, &program);
+        assert_eq!("4,3,2,1", Day17::solve_part1(&input).unwrap());
+
+        let listing = disassemble(&program);
+        let without_addresses: Vec<&str> = listing.lines().map(|l| &l[5..]).collect();
+        assert_eq!(Ok(program), assemble(&without_addresses.join("\n")));
+    }
+
+    #[test]
+    fn test_assemble_errors() {
+        let error = |source| assemble(source).unwrap_err().to_string();
+        assert_eq!(
+            "line 2: combo operand 7 is reserved\n    | out 7",
+            error("bxc\nout 7")
+        );
+        assert!(error("raw 5, 7").contains("reserved combo operand 7"));
+        assert!(error("bxc\nraw 3").starts_with("odd program length 3"));
+        assert!(error("jnz nowhere").contains("unknown label"));
+        assert!(error("raw 0,0,0,0,0,0,0,0\nfar: jnz far").contains("can only reach 0-7"));
+    }
+}
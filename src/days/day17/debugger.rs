@@ -0,0 +1,532 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use super::{
+    instruction::{disassemble, Instruction, Register},
+    Fault, Limits, Machine, Registers,
+};
+use crate::AocError;
+
+/// Steps `continue` takes before giving up on a program that never halts, the same
+/// as a machine running on its own.
+pub const STEP_LIMIT: usize = Limits::DEFAULT.steps;
+
+pub const HELP: &str = "\
+step [N]        Execute N instructions (default 1), stopping early at breakpoints
+continue        Run until the program halts or a breakpoint or watch triggers
+break <BP>      Stop before the instruction at an address (`12` or `ip=12`),
+                or when a register condition becomes true (`A==0`, `B>5`, `C!=3`)
+delete <N>      Remove breakpoint N
+watch <REG>     Stop whenever the register changes
+set <REG> <N>   Change a register
+regs            Show the registers
+list            Show the program, `=>` marking the next instruction and `*` breakpoints
+info            Show breakpoints and watches
+output          Show the output so far
+trace <FILE>    Write every executed instruction to FILE, tab-separated
+reset           Restart the program with the initial registers
+quit            Leave the debugger
+An empty line repeats the last step or continue.";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Comparison {
+    /// Two-character operators first, so that `<=` isn't read as `<`.
+    const SYMBOLS: [(&str, Comparison); 6] = [
+        ("==", Comparison::Eq),
+        ("!=", Comparison::Ne),
+        ("<=", Comparison::Le),
+        (">=", Comparison::Ge),
+        ("<", Comparison::Lt),
+        (">", Comparison::Gt),
+    ];
+
+    fn holds(self, left: u64, right: u64) -> bool {
+        match self {
+            Comparison::Eq => left == right,
+            Comparison::Ne => left != right,
+            Comparison::Lt => left < right,
+            Comparison::Le => left <= right,
+            Comparison::Gt => left > right,
+            Comparison::Ge => left >= right,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        Self::SYMBOLS.iter().find(|(_, c)| *c == self).unwrap().0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stops before executing the instruction at this address.
+    Ip(usize),
+    /// Stops after an instruction that makes the condition true.
+    Register {
+        register: Register,
+        comparison: Comparison,
+        value: u64,
+    },
+}
+
+impl Breakpoint {
+    /// Whether the instruction that took the registers from `before` to the current
+    /// state of `machine` triggers this breakpoint.
+    fn hit(&self, before: &Registers, machine: &Machine) -> bool {
+        match *self {
+            Breakpoint::Ip(ip) => machine.ip() == ip,
+            Breakpoint::Register {
+                register,
+                comparison,
+                value,
+            } => {
+                !comparison.holds(before.get(register), value)
+                    && comparison.holds(machine.registers.get(register), value)
+            }
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        let invalid = || format!("Invalid breakpoint: {s}");
+        let ip = s
+            .strip_prefix("ip")
+            .map(|rest| rest.trim_start_matches('=').trim())
+            .unwrap_or(s);
+        if let Ok(ip) = ip.parse() {
+            return Ok(Breakpoint::Ip(ip));
+        }
+
+        let (symbol, comparison) = Comparison::SYMBOLS
+            .into_iter()
+            .find(|(symbol, _)| s.contains(symbol))
+            .ok_or_else(invalid)?;
+        let (register, value) = s.split_once(symbol).ok_or_else(invalid)?;
+        Ok(Breakpoint::Register {
+            register: Register::from_name(register.trim()).ok_or_else(invalid)?,
+            comparison,
+            value: value.trim().parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Ip(ip) => write!(f, "ip={ip}"),
+            Breakpoint::Register {
+                register,
+                comparison,
+                value,
+            } => write!(f, "{register}{}{value}", comparison.symbol()),
+        }
+    }
+}
+
+/// One executed instruction, with the registers on either side of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub step: usize,
+    pub ip: usize,
+    pub opcode: u64,
+    pub operand: u64,
+    pub before: Registers,
+    pub after: Registers,
+    pub output: Option<u64>,
+}
+
+impl TraceEntry {
+    pub const HEADER: &str = "step\tip\topcode\toperand\tinstruction\tA\tB\tC\tA'\tB'\tC'\tout";
+}
+
+impl Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instruction = Instruction::decode(self.opcode, self.operand)
+            .map_or_else(|| "<invalid>".to_string(), |i| i.to_string());
+        let output = self.output.map_or(String::new(), |out| out.to_string());
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{instruction}\t{}\t{}\t{}\t{}\t{}\t{}\t{output}",
+            self.step,
+            self.ip,
+            self.opcode,
+            self.operand,
+            self.before.a,
+            self.before.b,
+            self.before.c,
+            self.after.a,
+            self.after.b,
+            self.after.c,
+        )
+    }
+}
+
+/// Why execution stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted,
+    /// Index into the breakpoints.
+    Breakpoint(usize),
+    Watch {
+        register: Register,
+        old: u64,
+        new: u64,
+    },
+    /// The requested number of steps ran without anything else stopping them.
+    Paused,
+    Fault(Fault),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Step(usize),
+    Continue,
+    Break(Breakpoint),
+    Delete(usize),
+    Watch(Register),
+    Set(Register, u64),
+    Registers,
+    List,
+    Info,
+    Output,
+    Trace(PathBuf),
+    Reset,
+    Help,
+    Quit,
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let (name, rest) = s.trim().split_once(' ').unwrap_or((s.trim(), ""));
+        let rest = rest.trim();
+        let register = |name: &str| {
+            Register::from_name(name).ok_or_else(|| format!("Invalid register: {name}"))
+        };
+        let number = |value: &str| {
+            value
+                .parse()
+                .map_err(|_| format!("Invalid number: {value}"))
+        };
+        Ok(match name {
+            "s" | "step" if rest.is_empty() => Command::Step(1),
+            "s" | "step" => Command::Step(number(rest)? as usize),
+            "c" | "continue" => Command::Continue,
+            "b" | "break" => Command::Break(rest.parse()?),
+            "d" | "delete" => Command::Delete(number(rest)? as usize),
+            "w" | "watch" => Command::Watch(register(rest)?),
+            "set" => {
+                let (name, value) = rest
+                    .split_once([' ', '='])
+                    .ok_or("set expects a register and a value")?;
+                let value = value.trim().trim_start_matches('=').trim();
+                Command::Set(register(name.trim())?, number(value)?)
+            }
+            "r" | "regs" => Command::Registers,
+            "l" | "list" => Command::List,
+            "i" | "info" => Command::Info,
+            "o" | "output" => Command::Output,
+            "t" | "trace" if !rest.is_empty() => Command::Trace(PathBuf::from(rest)),
+            "t" | "trace" => return Err("trace expects a file".to_string()),
+            "reset" => Command::Reset,
+            "h" | "help" => Command::Help,
+            "q" | "quit" => Command::Quit,
+            _ => return Err(format!("Unknown command: {name}, try `help`")),
+        })
+    }
+}
+
+/// Runs a [`Machine`] an instruction at a time, recording everything it executes.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    initial: Machine,
+    machine: Machine,
+    breakpoints: Vec<Breakpoint>,
+    watches: Vec<Register>,
+    trace: Vec<TraceEntry>,
+}
+
+impl Debugger {
+    /// Lifts the machine's limits, [`Debugger::run`] is bounded by its own.
+    pub fn new(mut machine: Machine) -> Self {
+        machine.limits = Limits::NONE;
+        Debugger {
+            initial: machine.clone(),
+            machine,
+            breakpoints: Vec::new(),
+            watches: Vec::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn watch(&mut self, register: Register) {
+        if !self.watches.contains(&register) {
+            self.watches.push(register);
+        }
+    }
+
+    /// Changes a register, also in the state [`Debugger::reset`] goes back to if the
+    /// program hasn't started yet.
+    pub fn set(&mut self, register: Register, value: u64) {
+        self.machine.registers.set(register, value);
+        if self.trace.is_empty() {
+            self.initial.registers.set(register, value);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.machine = self.initial.clone();
+        self.trace.clear();
+    }
+
+    /// Executes one instruction, or returns `None` if the program has halted. A faulting
+    /// instruction isn't executed, so the machine stays where the fault happened.
+    pub fn step(&mut self) -> Result<Option<&TraceEntry>, Fault> {
+        let (ip, before) = (self.machine.ip(), self.machine.registers);
+        let outputs = self.machine.stdout.len();
+        if !self.machine.step()? {
+            return Ok(None);
+        }
+        self.trace.push(TraceEntry {
+            step: self.trace.len() + 1,
+            ip,
+            opcode: self.machine.instructions[ip],
+            operand: self.machine.instructions[ip + 1],
+            before,
+            after: self.machine.registers,
+            output: self.machine.stdout.get(outputs).copied(),
+        });
+        Ok(self.trace.last())
+    }
+
+    /// Executes up to `limit` instructions, stopping early when the program halts or
+    /// faults, a watched register changes or a breakpoint is hit.
+    pub fn run(&mut self, limit: usize) -> Stop {
+        for _ in 0..limit {
+            let entry = match self.step() {
+                Ok(Some(entry)) => entry,
+                Ok(None) => return Stop::Halted,
+                Err(fault) => return Stop::Fault(fault),
+            };
+            let (before, after) = (entry.before, entry.after);
+            for &register in &self.watches {
+                let (old, new) = (before.get(register), after.get(register));
+                if old != new {
+                    return Stop::Watch { register, old, new };
+                }
+            }
+            let hit = |b: &Breakpoint| b.hit(&before, &self.machine);
+            if let Some(index) = self.breakpoints.iter().position(hit) {
+                return Stop::Breakpoint(index);
+            }
+        }
+        if self.machine.is_halted() {
+            Stop::Halted
+        } else {
+            Stop::Paused
+        }
+    }
+
+    pub fn trace_text(&self) -> String {
+        let mut text = format!("{}\n", TraceEntry::HEADER);
+        for entry in &self.trace {
+            text.push_str(&format!("{entry}\n"));
+        }
+        text
+    }
+
+    pub fn save_trace(&self, path: &Path) -> Result<(), AocError> {
+        fs::write(path, self.trace_text()).map_err(|source| AocError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// The next instruction and the registers, or the output once halted.
+    pub fn status(&self) -> String {
+        let machine = &self.machine;
+        if machine.is_halted() {
+            return format!("halted, output: {}", machine.get_stdout());
+        }
+        let ip = machine.ip();
+        let instruction =
+            Instruction::decode(machine.instructions[ip], machine.instructions[ip + 1])
+                .map_or_else(|| "<invalid>".to_string(), |i| i.to_string());
+        format!("{ip:>3}: {instruction:<12}  {}", machine.registers)
+    }
+
+    fn describe(&self, stop: Stop) -> String {
+        let reason = match stop {
+            Stop::Halted | Stop::Paused => None,
+            Stop::Breakpoint(index) => Some(format!(
+                "breakpoint {index} ({}) hit",
+                self.breakpoints[index]
+            )),
+            Stop::Watch { register, old, new } => Some(format!("{register}: {old} -> {new}")),
+            Stop::Fault(fault) => Some(format!("fault: {fault}")),
+        };
+        match reason {
+            Some(reason) => format!("{reason}\n{}", self.status()),
+            None => self.status(),
+        }
+    }
+
+    /// Carries out a command, returning what to show the user.
+    pub fn execute(&mut self, command: Command) -> String {
+        match command {
+            Command::Step(steps) => {
+                let stop = self.run(steps);
+                self.describe(stop)
+            }
+            Command::Continue => match self.run(STEP_LIMIT) {
+                Stop::Paused => {
+                    format!("still running after {STEP_LIMIT} steps\n{}", self.status())
+                }
+                stop => self.describe(stop),
+            },
+            Command::Break(breakpoint) => {
+                self.add_breakpoint(breakpoint);
+                format!("breakpoint {} at {breakpoint}", self.breakpoints.len() - 1)
+            }
+            Command::Delete(index) if index < self.breakpoints.len() => {
+                format!("deleted breakpoint {}", self.breakpoints.remove(index))
+            }
+            Command::Delete(index) => format!("no breakpoint {index}"),
+            Command::Watch(register) => {
+                self.watch(register);
+                format!("watching {register}")
+            }
+            Command::Set(register, value) => {
+                self.set(register, value);
+                self.status()
+            }
+            Command::Registers => self.machine.registers.to_string(),
+            Command::List => {
+                let program = &self.machine.instructions;
+                disassemble(program)
+                    .lines()
+                    .enumerate()
+                    .map(|(i, line)| {
+                        let address = 2 * i;
+                        let current = !self.machine.is_halted() && address == self.machine.ip();
+                        let breakpoint = self.breakpoints.contains(&Breakpoint::Ip(address));
+                        format!(
+                            "{}{} {line}\n",
+                            if current { "=>" } else { "  " },
+                            if breakpoint { "*" } else { " " }
+                        )
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            }
+            Command::Info => {
+                let mut info = Vec::new();
+                for (index, breakpoint) in self.breakpoints.iter().enumerate() {
+                    info.push(format!("breakpoint {index}: {breakpoint}"));
+                }
+                for register in &self.watches {
+                    info.push(format!("watch: {register}"));
+                }
+                if info.is_empty() {
+                    "no breakpoints or watches".to_string()
+                } else {
+                    info.join("\n")
+                }
+            }
+            Command::Output => self.machine.get_stdout(),
+            Command::Trace(path) => match self.save_trace(&path) {
+                Ok(()) => format!("wrote {} steps to {}", self.trace.len(), path.display()),
+                Err(e) => format!("error: {e}"),
+            },
+            Command::Reset => {
+                self.reset();
+                self.status()
+            }
+            Command::Help => HELP.to_string(),
+            Command::Quit => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day17::Day17, Solution};
+
+    const INPUT: &str = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+
+    #[test]
+    fn test_breakpoints_and_trace() {
+        let mut debugger = Debugger::new(Day17::parse(INPUT).unwrap());
+        debugger.add_breakpoint("ip=2".parse().unwrap());
+        debugger.add_breakpoint("A<10".parse().unwrap());
+
+        assert_eq!(Stop::Breakpoint(0), debugger.run(STEP_LIMIT));
+        assert_eq!(364, debugger.machine().registers.get(Register::A));
+        debugger.execute(Command::Delete(0));
+        assert_eq!(Stop::Breakpoint(0), debugger.run(STEP_LIMIT));
+        assert_eq!(5, debugger.machine().registers.get(Register::A));
+
+        debugger.execute(Command::Delete(0));
+        debugger.watch(Register::A);
+        assert_eq!(
+            Stop::Watch {
+                register: Register::A,
+                old: 5,
+                new: 2
+            },
+            debugger.run(STEP_LIMIT)
+        );
+
+        let trace = debugger.trace_text();
+        let mut lines = trace.lines().skip(1);
+        assert_eq!(
+            Some("1\t0\t0\t1\tadv A, 1\t729\t0\t0\t364\t0\t0\t"),
+            lines.next()
+        );
+        assert_eq!(
+            Some("2\t2\t5\t4\tout A\t364\t0\t0\t364\t0\t0\t4"),
+            lines.next()
+        );
+
+        assert_eq!(
+            Ok(Command::Set(Register::B, 7)),
+            "set b=7".parse::<Command>()
+        );
+        assert!("break D>1".parse::<Command>().is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use std::fmt::{self, Display};
+
+use super::{
+    divide,
+    instruction::{decode_program, Combo, Instruction, Register},
+};
+
+/// A register value in terms of the registers at the start of a loop iteration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Register(Register),
+    Const(u64),
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Box<Expr>, Box<Expr>),
+    /// The low 3 bits, i.e. `x % 8`.
+    Low3(Box<Expr>),
+}
+
+impl Expr {
+    fn shr(value: Expr, shift: Expr) -> Expr {
+        match (value, shift) {
+            (Expr::Const(value), Expr::Const(shift)) => Expr::Const(divide(value, shift)),
+            (value, Expr::Const(0)) => value,
+            (value, shift) => Expr::Shr(Box::new(value), Box::new(shift)),
+        }
+    }
+
+    fn xor(left: Expr, right: Expr) -> Expr {
+        match (left, right) {
+            (Expr::Const(left), Expr::Const(right)) => Expr::Const(left ^ right),
+            (value, Expr::Const(0)) | (Expr::Const(0), value) => value,
+            (left, right) => Expr::Xor(Box::new(left), Box::new(right)),
+        }
+    }
+
+    fn low3(value: Expr) -> Expr {
+        match value {
+            Expr::Const(value) => Expr::Const(value % 8),
+            value @ Expr::Low3(_) => value,
+            value => Expr::Low3(Box::new(value)),
+        }
+    }
+
+    fn is_atom(&self) -> bool {
+        matches!(self, Expr::Register(_) | Expr::Const(_))
+    }
+}
+
+/// Wraps compound subexpressions in parentheses.
+struct Operand<'a>(&'a Expr);
+
+impl Display for Operand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_atom() {
+            write!(f, "{}", self.0)
+        } else {
+            write!(f, "({})", self.0)
+        }
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Register(register) => write!(f, "{register}"),
+            Expr::Const(value) => write!(f, "{value}"),
+            Expr::Shr(value, shift) => write!(f, "{} >> {}", Operand(value), Operand(shift)),
+            Expr::Xor(left, right) => write!(f, "{} ^ {}", Operand(left), Operand(right)),
+            Expr::Low3(value) => write!(f, "{} & 7", Operand(value)),
+        }
+    }
+}
+
+fn combo_expr(registers: &[Expr; 3], combo: Combo) -> Option<Expr> {
+    match combo {
+        Combo::Literal(n) => Some(Expr::Const(n)),
+        Combo::Register(register) => Some(registers[register.index()].clone()),
+        Combo::Reserved => None,
+    }
+}
+
+/// What one pass through the loop body does: the values it outputs and the registers
+/// it leaves behind, all in terms of the registers at the start of the pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Iteration {
+    pub outputs: Vec<Expr>,
+    pub registers: [Expr; 3],
+}
+
+/// Symbolically runs a program that is a single `... jnz 0` loop through one
+/// iteration, or returns `None` for any other shape.
+pub fn loop_iteration(program: &[u64]) -> Option<Iteration> {
+    let decoded = decode_program(program);
+    let (last, body) = decoded.split_last()?;
+    if last.1 != Some(Instruction::Jnz(0)) {
+        return None;
+    }
+
+    let mut registers = Register::ALL.map(Expr::Register);
+    let mut outputs = Vec::new();
+    for &(_, instruction) in body {
+        let a = || registers[0].clone();
+        match instruction? {
+            Instruction::Adv(combo) => {
+                registers[0] = Expr::shr(a(), combo_expr(&registers, combo)?)
+            }
+            Instruction::Bdv(combo) => {
+                registers[1] = Expr::shr(a(), combo_expr(&registers, combo)?)
+            }
+            Instruction::Cdv(combo) => {
+                registers[2] = Expr::shr(a(), combo_expr(&registers, combo)?)
+            }
+            Instruction::Bxl(literal) => {
+                registers[1] = Expr::xor(registers[1].clone(), Expr::Const(literal))
+            }
+            Instruction::Bst(combo) => registers[1] = Expr::low3(combo_expr(&registers, combo)?),
+            Instruction::Bxc => {
+                registers[1] = Expr::xor(registers[1].clone(), registers[2].clone())
+            }
+            Instruction::Out(combo) => outputs.push(Expr::low3(combo_expr(&registers, combo)?)),
+            Instruction::Jnz(_) => return None,
+        }
+    }
+    Some(Iteration { outputs, registers })
+}
+
+fn statement(instruction: Option<Instruction>) -> String {
+    let Some(instruction) = instruction else {
+        return "invalid opcode".to_string();
+    };
+    match instruction {
+        Instruction::Adv(c) => format!("A = A >> {c}"),
+        Instruction::Bdv(c) => format!("B = A >> {c}"),
+        Instruction::Cdv(c) => format!("C = A >> {c}"),
+        Instruction::Bxl(literal) => format!("B = B ^ {literal}"),
+        Instruction::Bst(c) => format!("B = {c} & 7"),
+        Instruction::Bxc => "B = B ^ C".to_string(),
+        Instruction::Out(c) => format!("out({c} & 7)"),
+        Instruction::Jnz(target) => format!("if A != 0 goto {target}"),
+    }
+}
+
+/// Pseudo-code for the program. Single-loop programs are shown as one loop iteration
+/// with every output and register written in terms of the values at its start,
+/// anything else as one statement per instruction.
+pub fn decompile(program: &[u64]) -> String {
+    let Some(iteration) = loop_iteration(program) else {
+        return decode_program(program)
+            .into_iter()
+            .map(|(address, instruction)| format!("{address:>3}: {}\n", statement(instruction)))
+            .collect();
+    };
+
+    let mut code =
+        String::from("// right-hand sides read the registers as they were at `do`\ndo {\n");
+    for output in &iteration.outputs {
+        code.push_str(&format!("    out({output})\n"));
+    }
+    for (register, value) in Register::ALL.iter().zip(&iteration.registers) {
+        if *value != Expr::Register(*register) {
+            code.push_str(&format!("    {register} = {value}\n"));
+        }
+    }
+    code.push_str("} while A != 0\n");
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompile_loop() {
+        let program = [2, 4, 1, 2, 7, 5, 4, 7, 1, 3, 5, 5, 0, 3, 3, 0];
+        let expected = "// right-hand sides read the registers as they were at `do`
+do {
+    out(((((A & 7) ^ 2) ^ (A >> ((A & 7) ^ 2))) ^ 3) & 7)
+    A = A >> 3
+    B = (((A & 7) ^ 2) ^ (A >> ((A & 7) ^ 2))) ^ 3
+    C = A >> ((A & 7) ^ 2)
+} while A != 0
+";
+        assert_eq!(expected, decompile(&program));
+        assert_eq!("  0: out(A & 7)\n", decompile(&[5, 4]));
+        // Folds shifts the way the VM runs them, even past 32 bits.
+        assert_eq!(
+            Expr::Const(0),
+            Expr::shr(Expr::Const(1), Expr::Const(1 << 32))
+        );
+    }
+}
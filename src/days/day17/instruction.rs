@@ -0,0 +1,191 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+impl Register {
+    pub const ALL: [Register; 3] = [Register::A, Register::B, Register::C];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Parses `A`, `B` or `C`, in either case.
+    pub fn from_name(name: &str) -> Option<Register> {
+        match name {
+            "A" | "a" => Some(Register::A),
+            "B" | "b" => Some(Register::B),
+            "C" | "c" => Some(Register::C),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// A combo operand: 0-3 are literals, 4-6 read a register and 7 is reserved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combo {
+    Literal(u64),
+    Register(Register),
+    Reserved,
+}
+
+impl Combo {
+    pub fn decode(operand: u64) -> Self {
+        match operand {
+            n @ 0..=3 => Combo::Literal(n),
+            4 => Combo::Register(Register::A),
+            5 => Combo::Register(Register::B),
+            6 => Combo::Register(Register::C),
+            _ => Combo::Reserved,
+        }
+    }
+}
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Combo::Literal(n) => write!(f, "{n}"),
+            Combo::Register(register) => write!(f, "{register}"),
+            Combo::Reserved => write!(f, "<reserved 7>"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// `A = A >> combo`
+    Adv(Combo),
+    /// `B = B ^ literal`
+    Bxl(u64),
+    /// `B = combo & 7`
+    Bst(Combo),
+    /// Jump to `literal` unless A is 0.
+    Jnz(u64),
+    /// `B = B ^ C`, the operand is ignored.
+    Bxc,
+    /// Output `combo & 7`.
+    Out(Combo),
+    /// `B = A >> combo`
+    Bdv(Combo),
+    /// `C = A >> combo`
+    Cdv(Combo),
+}
+
+impl Instruction {
+    pub const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+    /// Decodes a 3-bit opcode and its operand, `None` if the opcode is out of range.
+    pub fn decode(opcode: u64, operand: u64) -> Option<Self> {
+        let combo = Combo::decode(operand);
+        Some(match opcode {
+            0 => Instruction::Adv(combo),
+            1 => Instruction::Bxl(operand),
+            2 => Instruction::Bst(combo),
+            3 => Instruction::Jnz(operand),
+            4 => Instruction::Bxc,
+            5 => Instruction::Out(combo),
+            6 => Instruction::Bdv(combo),
+            7 => Instruction::Cdv(combo),
+            _ => return None,
+        })
+    }
+
+    pub fn opcode(self) -> u64 {
+        match self {
+            Instruction::Adv(_) => 0,
+            Instruction::Bxl(_) => 1,
+            Instruction::Bst(_) => 2,
+            Instruction::Jnz(_) => 3,
+            Instruction::Bxc => 4,
+            Instruction::Out(_) => 5,
+            Instruction::Bdv(_) => 6,
+            Instruction::Cdv(_) => 7,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        Self::MNEMONICS[self.opcode() as usize]
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mnemonic = self.mnemonic();
+        match self {
+            Instruction::Adv(combo) => write!(f, "{mnemonic} A, {combo}"),
+            Instruction::Bdv(combo) => write!(f, "{mnemonic} B, {combo}"),
+            Instruction::Cdv(combo) => write!(f, "{mnemonic} C, {combo}"),
+            Instruction::Bst(combo) | Instruction::Out(combo) => write!(f, "{mnemonic} {combo}"),
+            Instruction::Bxl(literal) | Instruction::Jnz(literal) => {
+                write!(f, "{mnemonic} {literal}")
+            }
+            Instruction::Bxc => write!(f, "{mnemonic}"),
+        }
+    }
+}
+
+/// Decodes the program into `(address, instruction)` pairs. A trailing opcode without
+/// an operand is never executed and is left out.
+pub fn decode_program(program: &[u64]) -> Vec<(usize, Option<Instruction>)> {
+    program
+        .chunks_exact(2)
+        .enumerate()
+        .map(|(i, pair)| (2 * i, Instruction::decode(pair[0], pair[1])))
+        .collect()
+}
+
+/// One instruction per line, prefixed with its address.
+pub fn disassemble(program: &[u64]) -> String {
+    let mut listing = String::new();
+    for (address, instruction) in decode_program(program) {
+        match instruction {
+            Some(instruction) => listing.push_str(&format!("{address:>3}: {instruction}\n")),
+            None => listing.push_str(&format!(
+                "{address:>3}: <invalid opcode {}>\n",
+                program[address]
+            )),
+        }
+    }
+    if program.len() % 2 == 1 {
+        listing.push_str(&format!(
+            "{:>3}: <missing operand for {}>\n",
+            program.len() - 1,
+            program[program.len() - 1]
+        ));
+    }
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disassemble() {
+        let listing = disassemble(&[2, 4, 1, 2, 7, 5, 4, 7, 5, 5, 0, 3, 3, 0, 5]);
+        let expected = "  0: bst A
+  2: bxl 2
+  4: cdv C, B
+  6: bxc
+  8: out B
+ 10: adv A, 3
+ 12: jnz 0
+ 14: <missing operand for 5>
+";
+        assert_eq!(expected, listing);
+        assert_eq!(
+            "out <reserved 7>",
+            Instruction::decode(5, 7).unwrap().to_string()
+        );
+    }
+}
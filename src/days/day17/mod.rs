@@ -0,0 +1,453 @@
+pub mod assembler;
+pub mod debugger;
+pub mod decompile;
+pub mod instruction;
+pub mod symbolic;
+
+use std::fmt::{self, Display};
+
+use crate::{
+    parse::{lines, Line},
+    Answer, ParseError, Solution, Unsolvable,
+};
+use instruction::{Combo, Instruction, Register};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    a: u64,
+    b: u64,
+    c: u64,
+}
+
+impl Registers {
+    pub fn get(&self, register: Register) -> u64 {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+
+    pub fn set(&mut self, register: Register, value: u64) {
+        match register {
+            Register::A => self.a = value,
+            Register::B => self.b = value,
+            Register::C => self.c = value,
+        }
+    }
+}
+
+impl Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "A={} B={} C={}", self.a, self.b, self.c)
+    }
+}
+
+/// Bounds on a run, so that programs which never halt or never stop printing fail
+/// with a [`Fault`] instead of hanging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub steps: usize,
+    pub outputs: usize,
+}
+
+impl Limits {
+    pub const NONE: Limits = Limits {
+        steps: usize::MAX,
+        outputs: usize::MAX,
+    };
+
+    /// What a [`Machine`] starts with, see [`Limits::default`].
+    pub const DEFAULT: Limits = Limits {
+        steps: 1_000_000,
+        outputs: 100_000,
+    };
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits::DEFAULT
+    }
+}
+
+/// A program that ran off its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Halt {
+    pub steps: usize,
+}
+
+/// Why a program stopped without halting. `ip` is the address of the offending instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    ReservedOperand { ip: usize },
+    BadOpcode { ip: usize, opcode: u64 },
+    StepLimit(usize),
+    OutputLimit(usize),
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fault::ReservedOperand { ip } => write!(f, "reserved combo operand 7 at address {ip}"),
+            Fault::BadOpcode { ip, opcode } => write!(f, "invalid opcode {opcode} at address {ip}"),
+            Fault::StepLimit(limit) => write!(f, "no halt within {limit} steps"),
+            Fault::OutputLimit(limit) => write!(f, "more than {limit} outputs"),
+        }
+    }
+}
+
+impl std::error::Error for Fault {}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub registers: Registers,
+    pub instructions: Vec<u64>,
+    pub limits: Limits,
+    i_ptr: usize,
+    steps: usize,
+    pub stdout: Vec<u64>,
+}
+
+/// `a / 2^shift`, which is 0 once the shift reaches past all of `a`'s bits.
+fn divide(a: u64, shift: u64) -> u64 {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|shift| a.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+impl Machine {
+    pub fn new(registers: Registers, instructions: Vec<u64>) -> Self {
+        Machine {
+            registers,
+            instructions,
+            limits: Limits::default(),
+            i_ptr: 0,
+            steps: 0,
+            stdout: Vec::new(),
+        }
+    }
+    fn combo(&self, combo: Combo) -> Result<u64, Fault> {
+        match combo {
+            Combo::Literal(n) => Ok(n),
+            Combo::Register(register) => Ok(self.registers.get(register)),
+            Combo::Reserved => Err(Fault::ReservedOperand { ip: self.i_ptr }),
+        }
+    }
+    /// Only changes the machine once the instruction is known not to fault.
+    fn execute_instruction(&mut self, opcode: u64, operand: u64) -> Result<(), Fault> {
+        let ip = self.i_ptr;
+        let instruction =
+            Instruction::decode(opcode, operand).ok_or(Fault::BadOpcode { ip, opcode })?;
+        let a = self.registers.a;
+        let mut next = ip + 2;
+        match instruction {
+            Instruction::Adv(combo) => self.registers.a = divide(a, self.combo(combo)?),
+            Instruction::Bxl(literal) => self.registers.b ^= literal,
+            Instruction::Bst(combo) => self.registers.b = self.combo(combo)? % 8,
+            Instruction::Jnz(target) if a != 0 => next = target as usize,
+            Instruction::Jnz(_) => {}
+            Instruction::Bxc => self.registers.b ^= self.registers.c,
+            Instruction::Out(combo) => {
+                if self.stdout.len() >= self.limits.outputs {
+                    return Err(Fault::OutputLimit(self.limits.outputs));
+                }
+                self.stdout.push(self.combo(combo)? % 8)
+            }
+            Instruction::Bdv(combo) => self.registers.b = divide(a, self.combo(combo)?),
+            Instruction::Cdv(combo) => self.registers.c = divide(a, self.combo(combo)?),
+        };
+        self.i_ptr = next;
+        Ok(())
+    }
+    pub fn ip(&self) -> usize {
+        self.i_ptr
+    }
+    /// Instructions executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+    /// The program halts once the instruction pointer leaves it, or points at a
+    /// trailing opcode without an operand.
+    pub fn is_halted(&self) -> bool {
+        self.i_ptr + 1 >= self.instructions.len()
+    }
+    /// Executes the instruction at the instruction pointer, or returns false if the
+    /// program has halted. A fault leaves the machine as it was before the instruction.
+    pub fn step(&mut self) -> Result<bool, Fault> {
+        if self.is_halted() {
+            return Ok(false);
+        }
+        if self.steps >= self.limits.steps {
+            return Err(Fault::StepLimit(self.limits.steps));
+        }
+        let ip = self.i_ptr;
+        self.execute_instruction(self.instructions[ip], self.instructions[ip + 1])?;
+        self.steps += 1;
+        Ok(true)
+    }
+    /// Runs until the program halts or faults.
+    pub fn run(&mut self) -> Result<Halt, Fault> {
+        while self.step()? {}
+        Ok(Halt { steps: self.steps })
+    }
+    /// Runs the program from the start with register A set to `a`, checking whether it
+    /// outputs exactly `expected`. Gives up as soon as an output differs, on any fault,
+    /// or when `OUTPUT_STEPS` instructions go by without an output or a halt.
+    fn outputs_with_a(&self, a: u64, expected: &[u64]) -> bool {
+        self.try_a(a, expected).0
+    }
+    /// [`Machine::outputs_with_a`], along with the number of instructions it ran.
+    fn try_a(&self, a: u64, expected: &[u64]) -> (bool, usize) {
+        let mut machine = Machine::new(
+            Registers {
+                a,
+                ..self.registers
+            },
+            self.instructions.clone(),
+        );
+        machine.limits = Limits {
+            steps: OUTPUT_STEPS,
+            outputs: expected.len(),
+        };
+        let mut printed = 0;
+        loop {
+            match machine.step() {
+                Ok(true) => {}
+                Ok(false) => return (machine.stdout == expected, machine.steps),
+                Err(_) => return (false, machine.steps),
+            }
+            if machine.stdout.len() > printed {
+                if machine.stdout[printed] != expected[printed] {
+                    return (false, machine.steps);
+                }
+                printed += 1;
+                machine.limits.steps = machine.steps + OUTPUT_STEPS;
+            }
+        }
+    }
+    // Puzzle programs are a single loop: `jnz 0` at the end, with exactly one `adv 3`
+    // and one `out` per iteration, so each output only depends on A's remaining bits.
+    fn is_octal_loop(&self) -> bool {
+        let pairs: Vec<&[u64]> = self.instructions.chunks(2).collect();
+        let count = |opcode: u64| pairs.iter().filter(|pair| pair[0] == opcode).count();
+        pairs.last() == Some(&[3, 0].as_slice())
+            && count(3) == 1
+            && count(5) == 1
+            && count(0) == 1
+            && pairs.contains(&[0, 3].as_slice())
+    }
+    fn get_stdout(&self) -> String {
+        self.stdout
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+/// Step limit for each path the symbolic search explores, far more than a puzzle
+/// program needs for a 16-value output.
+const SEARCH_STEPS: usize = 100_000;
+/// Steps a candidate A gets to print its next output or halt, far more than one
+/// iteration of a puzzle program's loop takes.
+const OUTPUT_STEPS: usize = 1_000;
+const BRUTE_FORCE_LIMIT: u64 = 1 << 24;
+const BRUTE_FORCE_STEPS: usize = 1 << 25;
+
+// The last iteration of the loop sees only the top 3 bits of A and prints the last
+// output, the one before sees the top 6 bits and prints the last two, and so on. So
+// build A from the top, 3 bits at a time, keeping every candidate that prints the
+// matching suffix of the program. Candidates are tried in increasing order, so the
+// smallest one left at the end is the answer.
+fn octal_search(machine: &Machine) -> Option<u64> {
+    let program = &machine.instructions;
+    let mut candidates = vec![0];
+    for i in (0..program.len()).rev() {
+        candidates = candidates
+            .iter()
+            .flat_map(|a| (0..8).map(move |digit| a * 8 + digit))
+            .filter(|&a| a != 0 && machine.outputs_with_a(a, &program[i..]))
+            .collect();
+    }
+    candidates.into_iter().min()
+}
+
+/// Tries every A below `BRUTE_FORCE_LIMIT`, giving up once the candidates have run
+/// `BRUTE_FORCE_STEPS` instructions between them.
+fn brute_force(machine: &Machine) -> Result<u64, Unsolvable> {
+    let mut steps = 0;
+    for a in 1..BRUTE_FORCE_LIMIT {
+        let (matches, taken) = machine.try_a(a, &machine.instructions);
+        if matches {
+            return Ok(a);
+        }
+        steps += taken;
+        if steps >= BRUTE_FORCE_STEPS {
+            return Err(Unsolvable::new(format!(
+                "gave up after {BRUTE_FORCE_STEPS} steps, having tried every A up to {a}"
+            )));
+        }
+    }
+    Err(Unsolvable::new(format!(
+        "no value of A below {BRUTE_FORCE_LIMIT} makes the program output itself"
+    )))
+}
+
+fn parse_input(input: &str) -> Result<Machine, ParseError> {
+    let sections: Vec<Line> = lines(input).collect();
+    let [a, b, c, program] = sections.as_slice() else {
+        return Err(ParseError::new("expected three registers and a program"));
+    };
+    let register = |line: &Line, name: &str| {
+        let (label, value) = line.split_once(": ")?;
+        if label != format!("Register {name}") {
+            return Err(line.error(format!("expected register {name}")));
+        }
+        line.parse::<u64>(value)
+    };
+    let (a, b, c) = (register(a, "A")?, register(b, "B")?, register(c, "C")?);
+
+    let (label, values) = program.split_once(": ")?;
+    if label != "Program" {
+        return Err(program.error("expected program"));
+    }
+    let instructions = values
+        .split(",")
+        .map(|x| match program.parse::<u64>(x)? {
+            n @ 0..=7 => Ok(n),
+            n => Err(program.error(format!("`{n}` is not a 3-bit number"))),
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok(Machine::new(Registers { a, b, c }, instructions))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed = Machine;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(machine: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut machine = machine.clone();
+        machine
+            .run()
+            .map_err(|fault| Unsolvable::new(format!("the program failed: {fault}")))?;
+        Ok(machine.get_stdout())
+    }
+
+    fn part2(machine: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        machine
+            .is_octal_loop()
+            .then(|| octal_search(machine))
+            .flatten()
+            .or_else(|| symbolic::solve(machine, &machine.instructions))
+            .map_or_else(|| brute_force(machine), Ok)
+            .map(|a| a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0";
+        assert_eq!("4,6,3,5,6,3,5,2,1,0", Day17::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0";
+
+        assert_eq!("117440", Day17::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2_brute_force() {
+        // Prints A in octal, then `jnz 3`. A is 0 by then, but on the branch where it
+        // isn't, offset 3 decodes as `jnz 3` to itself, so the symbolic search gives up.
+        let input = "Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 5,4,0,3,3,0,3,3";
+        let machine = Day17::parse(input).unwrap();
+        assert!(!machine.is_octal_loop());
+        assert_eq!(None, symbolic::solve(&machine, &machine.instructions));
+        assert_eq!("7091749", Day17::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2_gives_up() {
+        // Spins on `bxl 1` forever without printing, whatever A is.
+        let input = "Register A: 0
+Register B: 0
+Register C: 0
+
+Program: 1,1,3,0";
+        let start = Instant::now();
+        let error = Day17::solve_part2(input).unwrap_err().to_string();
+        assert!(error.contains("gave up"));
+        // A few seconds in a debug build, rather than the hours trying every A would take.
+        assert!(start.elapsed() < Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_outputs_with_a() {
+        let machine = |program: Vec<u64>| Machine::new(Registers::default(), program);
+        let binary = machine(vec![0, 1, 5, 4, 3, 0]);
+        assert!(binary.outputs_with_a(6, &[3, 1, 0]));
+        assert!(!binary.outputs_with_a(6, &[3, 2, 0]));
+        // Prints A and then spins on `jnz 2` without printing anything else.
+        let stuck = machine(vec![5, 4, 3, 2]);
+        assert!(!stuck.outputs_with_a(5, &[5, 5]));
+        assert!(stuck.outputs_with_a(0, &[0]));
+    }
+
+    #[test]
+    fn test_faults() {
+        let registers = Registers { a: 1, b: 0, c: 0 };
+        let run = |program: Vec<u64>, limits| {
+            let mut machine = Machine::new(registers, program);
+            machine.limits = limits;
+            machine.run()
+        };
+        let limits = Limits {
+            steps: 100,
+            outputs: 10,
+        };
+        assert_eq!(Ok(Halt { steps: 2 }), run(vec![1, 7, 5, 5], limits));
+        assert_eq!(
+            Err(Fault::ReservedOperand { ip: 2 }),
+            run(vec![1, 7, 2, 7], limits)
+        );
+        assert_eq!(
+            Err(Fault::BadOpcode { ip: 0, opcode: 9 }),
+            run(vec![9, 0], limits)
+        );
+        assert_eq!(Err(Fault::StepLimit(100)), run(vec![3, 0], limits));
+        assert_eq!(Err(Fault::OutputLimit(10)), run(vec![5, 4, 3, 0], limits));
+        assert_eq!(0, divide(1, 100));
+
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7";
+        assert!(Day17::solve_part1(input).is_err());
+    }
+}
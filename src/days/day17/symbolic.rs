@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+
+use super::{
+    instruction::{Combo, Instruction},
+    Machine, SEARCH_STEPS,
+};
+
+/// Paths through the program explored before giving up, each `jnz` on a value of A
+/// that isn't known yet forking one.
+const MAX_PATHS: usize = 10_000;
+
+/// A node in the [`Circuit`].
+type Bit = usize;
+
+/// A 64-bit register, least significant bit first.
+type Word = [Bit; 64];
+
+const FALSE: Bit = 0;
+const TRUE: Bit = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node {
+    Const(bool),
+    /// Bit `n` of the initial value of A.
+    Var(usize),
+    Not(Bit),
+    And(Bit, Bit),
+    Or(Bit, Bit),
+    Xor(Bit, Bit),
+}
+
+/// Boolean expressions over the bits of A. Identical expressions share a node, and
+/// constants are folded as the expressions are built.
+struct Circuit {
+    nodes: Vec<Node>,
+    ids: HashMap<Node, Bit>,
+}
+
+impl Circuit {
+    fn new() -> Self {
+        let mut circuit = Circuit {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+        };
+        circuit.node(Node::Const(false));
+        circuit.node(Node::Const(true));
+        circuit
+    }
+
+    fn node(&mut self, node: Node) -> Bit {
+        *self.ids.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        })
+    }
+
+    fn constant(value: bool) -> Bit {
+        if value {
+            TRUE
+        } else {
+            FALSE
+        }
+    }
+
+    fn not(&mut self, a: Bit) -> Bit {
+        match self.nodes[a] {
+            Node::Const(value) => Circuit::constant(!value),
+            Node::Not(inner) => inner,
+            _ => self.node(Node::Not(a)),
+        }
+    }
+
+    fn and(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (FALSE, _) | (_, FALSE) => FALSE,
+            (TRUE, x) | (x, TRUE) => x,
+            _ if a == b => a,
+            _ => self.node(Node::And(a.min(b), a.max(b))),
+        }
+    }
+
+    fn or(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (TRUE, _) | (_, TRUE) => TRUE,
+            (FALSE, x) | (x, FALSE) => x,
+            _ if a == b => a,
+            _ => self.node(Node::Or(a.min(b), a.max(b))),
+        }
+    }
+
+    fn xor(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (FALSE, x) | (x, FALSE) => x,
+            (TRUE, x) | (x, TRUE) => self.not(x),
+            _ if a == b => FALSE,
+            _ => self.node(Node::Xor(a.min(b), a.max(b))),
+        }
+    }
+
+    fn any(&mut self, bits: &[Bit]) -> Bit {
+        bits.iter().fold(FALSE, |any, &bit| self.or(any, bit))
+    }
+
+    /// `bit == value` as a bit of its own.
+    fn equals(&mut self, bit: Bit, value: bool) -> Bit {
+        if value {
+            bit
+        } else {
+            self.not(bit)
+        }
+    }
+
+    fn word(value: u64) -> Word {
+        std::array::from_fn(|i| Circuit::constant(value >> i & 1 == 1))
+    }
+
+    fn xor_words(&mut self, a: &Word, b: &Word) -> Word {
+        std::array::from_fn(|i| self.xor(a[i], b[i]))
+    }
+
+    fn low3(word: &Word) -> Word {
+        std::array::from_fn(|i| if i < 3 { word[i] } else { FALSE })
+    }
+
+    /// `word >> amount`, as a choice between every shift `amount` can still take.
+    fn shr(&mut self, word: &Word, amount: &Word) -> Word {
+        let past_end = self.any(&amount[6..]);
+        let in_range = self.not(past_end);
+        let mut shifted = [FALSE; 64];
+        for shift in 0..64 {
+            let selected = (0..6).fold(in_range, |selected, j| {
+                let bit = self.equals(amount[j], shift >> j & 1 == 1);
+                self.and(selected, bit)
+            });
+            if selected == FALSE {
+                continue;
+            }
+            for i in 0..64 - shift {
+                let bit = self.and(selected, word[i + shift]);
+                shifted[i] = self.or(shifted[i], bit);
+            }
+        }
+        shifted
+    }
+
+    /// Three-valued evaluation under a partial assignment: `None` when the value
+    /// depends on bits that aren't assigned yet.
+    fn eval(
+        &self,
+        bit: Bit,
+        vars: &[Option<bool>; 64],
+        memo: &mut [Option<Option<bool>>],
+    ) -> Option<bool> {
+        if let Some(value) = memo[bit] {
+            return value;
+        }
+        let value = match self.nodes[bit] {
+            Node::Const(value) => Some(value),
+            Node::Var(n) => vars[n],
+            Node::Not(a) => self.eval(a, vars, memo).map(|a| !a),
+            Node::And(a, b) => match (self.eval(a, vars, memo), self.eval(b, vars, memo)) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            },
+            Node::Or(a, b) => match (self.eval(a, vars, memo), self.eval(b, vars, memo)) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            },
+            Node::Xor(a, b) => match (self.eval(a, vars, memo), self.eval(b, vars, memo)) {
+                (Some(a), Some(b)) => Some(a ^ b),
+                _ => None,
+            },
+        };
+        memo[bit] = Some(value);
+        value
+    }
+}
+
+/// One path through the program, with the constraints on A that lead down it.
+#[derive(Clone)]
+struct State {
+    registers: [Word; 3],
+    ip: usize,
+    steps: usize,
+    outputs: usize,
+    constraints: Vec<Bit>,
+}
+
+impl State {
+    /// Adds a constraint, returning false if it can never hold.
+    fn require(&mut self, bit: Bit) -> bool {
+        match bit {
+            FALSE => false,
+            TRUE => true,
+            _ => {
+                self.constraints.push(bit);
+                true
+            }
+        }
+    }
+
+    fn combo(&self, combo: Combo) -> Option<Word> {
+        match combo {
+            Combo::Literal(n) => Some(Circuit::word(n)),
+            Combo::Register(register) => Some(self.registers[register.index()]),
+            Combo::Reserved => None,
+        }
+    }
+}
+
+/// Runs the program with A unknown, following every path that outputs a prefix of
+/// `expected`. Returns the constraints of each path that outputs all of it and halts.
+fn explore(circuit: &mut Circuit, machine: &Machine, expected: &[u64]) -> Option<Vec<Vec<Bit>>> {
+    let program = &machine.instructions;
+    let registers = [
+        std::array::from_fn(|i| circuit.node(Node::Var(i))),
+        Circuit::word(machine.registers.b),
+        Circuit::word(machine.registers.c),
+    ];
+    let mut stack = vec![State {
+        registers,
+        ip: 0,
+        steps: 0,
+        outputs: 0,
+        constraints: Vec::new(),
+    }];
+    let mut solutions = Vec::new();
+    let mut paths = 1;
+
+    while let Some(mut state) = stack.pop() {
+        loop {
+            if state.ip + 1 >= program.len() {
+                if state.outputs == expected.len() {
+                    solutions.push(state.constraints);
+                }
+                break;
+            }
+            if state.steps == SEARCH_STEPS {
+                return None;
+            }
+            state.steps += 1;
+
+            // Paths that fault can't produce the output, so they are dropped.
+            let Some(instruction) = Instruction::decode(program[state.ip], program[state.ip + 1])
+            else {
+                break;
+            };
+            let [a, b, c] = state.registers;
+            let next = state.ip + 2;
+            match instruction {
+                Instruction::Adv(combo) | Instruction::Bdv(combo) | Instruction::Cdv(combo) => {
+                    let Some(shift) = state.combo(combo) else {
+                        break;
+                    };
+                    let target = match instruction {
+                        Instruction::Adv(_) => 0,
+                        Instruction::Bdv(_) => 1,
+                        _ => 2,
+                    };
+                    state.registers[target] = circuit.shr(&a, &shift);
+                }
+                Instruction::Bxl(literal) => {
+                    state.registers[1] = circuit.xor_words(&b, &Circuit::word(literal))
+                }
+                Instruction::Bst(combo) => {
+                    let Some(value) = state.combo(combo) else {
+                        break;
+                    };
+                    state.registers[1] = Circuit::low3(&value);
+                }
+                Instruction::Bxc => state.registers[1] = circuit.xor_words(&b, &c),
+                Instruction::Out(combo) => {
+                    let Some(value) = state.combo(combo) else {
+                        break;
+                    };
+                    let Some(&digit) = expected.get(state.outputs) else {
+                        break;
+                    };
+                    state.outputs += 1;
+                    let matches = (0..3).all(|i| {
+                        let bit = circuit.equals(value[i], digit >> i & 1 == 1);
+                        state.require(bit)
+                    });
+                    if !matches {
+                        break;
+                    }
+                }
+                Instruction::Jnz(target) => {
+                    let nonzero = circuit.any(&a);
+                    if nonzero != TRUE {
+                        // Falling through needs every bit of A to be 0.
+                        let mut fall_through = state.clone();
+                        fall_through.ip = next;
+                        let zero = a.iter().all(|&bit| {
+                            let bit = circuit.not(bit);
+                            fall_through.require(bit)
+                        });
+                        if zero {
+                            paths += 1;
+                            if paths > MAX_PATHS {
+                                return None;
+                            }
+                            stack.push(fall_through);
+                        }
+                        if !state.require(nonzero) {
+                            break;
+                        }
+                    }
+                    state.ip = target as usize;
+                    continue;
+                }
+            }
+            state.ip = next;
+        }
+    }
+    Some(solutions)
+}
+
+/// The smallest A satisfying every constraint, found by assigning its bits from the
+/// most significant down, trying 0 before 1 and backtracking as soon as a constraint
+/// is known to fail.
+fn minimise(circuit: &Circuit, constraints: &[Bit]) -> Option<u64> {
+    fn assign(
+        circuit: &Circuit,
+        constraints: &[Bit],
+        vars: &mut [Option<bool>; 64],
+        memo: &mut Vec<Option<Option<bool>>>,
+        bit: usize,
+    ) -> bool {
+        memo.fill(None);
+        let values: Vec<Option<bool>> = constraints
+            .iter()
+            .map(|&c| circuit.eval(c, vars, memo))
+            .collect();
+        if values.contains(&Some(false)) {
+            return false;
+        }
+        if values.iter().all(|&value| value == Some(true)) {
+            // Whatever is left unassigned can stay 0.
+            (0..bit).for_each(|i| vars[i] = Some(false));
+            return true;
+        }
+        if bit == 0 {
+            return false;
+        }
+        for value in [false, true] {
+            vars[bit - 1] = Some(value);
+            if assign(circuit, constraints, vars, memo, bit - 1) {
+                return true;
+            }
+        }
+        vars[bit - 1] = None;
+        false
+    }
+
+    let mut vars = [None; 64];
+    let mut memo = vec![None; circuit.nodes.len()];
+    assign(circuit, constraints, &mut vars, &mut memo, 64)
+        .then(|| (0..64).fold(0, |a, i| a | (vars[i].unwrap() as u64) << i))
+}
+
+/// The smallest positive initial A for which the program outputs exactly `expected`,
+/// for any program shape. `None` if there is no such A, or if the program forks into
+/// too many paths or runs too long on one to explore them all.
+pub fn solve(machine: &Machine, expected: &[u64]) -> Option<u64> {
+    let mut circuit = Circuit::new();
+    let paths = explore(&mut circuit, machine, expected)?;
+    let vars: Vec<Bit> = (0..64).map(|i| circuit.node(Node::Var(i))).collect();
+    let positive = circuit.any(&vars);
+    paths
+        .into_iter()
+        .filter_map(|mut constraints| {
+            constraints.push(positive);
+            minimise(&circuit, &constraints)
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day17::Day17, Solution};
+
+    fn machine(program: &str) -> Machine {
+        let input = format!("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: {program}");
+        Day17::parse(&input).unwrap()
+    }
+
+    #[test]
+    fn test_solve() {
+        let quine = machine("2,4,1,2,7,5,4,7,1,3,5,5,0,3,3,0");
+        let program = quine.instructions.clone();
+        assert_eq!(Some(37221274271220), solve(&quine, &program));
+
+        // Shifting one bit per output, which the octal search can't handle.
+        let binary = machine("0,1,5,4,3,0");
+        assert_eq!(Some(6), solve(&binary, &[3, 1, 0]));
+        assert_eq!(None, solve(&binary, &[4, 6, 3]));
+    }
+}
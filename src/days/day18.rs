@@ -0,0 +1,112 @@
+use crate::{parse::lines, search, Answer, Grid, ParseError, Point, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let bytes = lines(input)
+        .map(|line| {
+            let (x, y) = line.split_once(",")?;
+            Ok((line.parse(x)?, line.parse(y)?))
+        })
+        .collect::<Result<Vec<(usize, usize)>, ParseError>>()?;
+    if bytes.is_empty() {
+        return Err(ParseError::new("no falling bytes"));
+    }
+    Ok(bytes)
+}
+
+fn solve_for_bytes(input: &[(usize, usize)]) -> Option<usize> {
+    let height = input.iter().map(|c| c.1).max().unwrap() + 1;
+    let width = input.iter().map(|c| c.0).max().unwrap() + 1;
+
+    let mut grid = Grid::filled(width, height, false);
+
+    for &(c, r) in input {
+        grid[Point::new(r, c)] = true;
+    }
+
+    let exit = Point::new(height - 1, width - 1);
+    let open = |&p: &Point| grid.neighbours4(p).filter(|&next| !grid[next]);
+    search::bfs(Point::new(0, 0), open, |&p| p == exit).map(|(_, steps)| steps)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed = Vec<(usize, usize)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut bytes_to_read = 1024;
+        if cfg!(test) {
+            bytes_to_read = 12;
+        }
+        let input: &[(usize, usize)] = input
+            .get(..bytes_to_read)
+            .ok_or_else(|| Unsolvable::new(format!("fewer than {bytes_to_read} bytes")))?;
+        solve_for_bytes(input)
+            .map(|steps| steps.to_string())
+            .ok_or_else(|| Unsolvable::new("no path to the exit"))
+    }
+
+    // Classic binary search, how I missed thee
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        if solve_for_bytes(input).is_some() {
+            return Err(Unsolvable::new("the exit is never cut off"));
+        }
+        let mut start = 0;
+        let mut end = input.len() - 1;
+        while start < end {
+            let mid = start + (end - start) / 2;
+            if solve_for_bytes(&input[..=mid]).is_some() {
+                start = mid + 1;
+            } else {
+                end = mid;
+            }
+        }
+        Ok(format!("{},{}", input[start].0, input[start].1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("22", Day18::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("6,1", Day18::solve_part2(INPUT).unwrap());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{parse::expect_sections, Answer, ParseError, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let [patterns, designs] = expect_sections(input, ["towel patterns", "designs"])?;
+    if let Some(line) = patterns.get(1) {
+        return Err(line.error("towel patterns must be on a single line"));
+    }
+    let patterns = patterns[0].text.split(", ").map(String::from).collect();
+    let designs = designs.iter().map(|line| line.text.to_string()).collect();
+    Ok((patterns, designs))
+}
+
+fn min_patterns_for_design(
+    design: &str,
+    patterns: &[String],
+    i: usize,
+    cache: &mut [Option<u64>],
+) -> u64 {
+    if i == design.len() {
+        return 1;
+    }
+    if let Some(v) = cache[i] {
+        return v;
+    }
+
+    let mut ans = 0;
+    for idx in i..design.len() {
+        let ndesign = &design[i..=idx];
+        if patterns.iter().any(|pattern| pattern == ndesign) {
+            ans += min_patterns_for_design(design, patterns, idx + 1, cache);
+        }
+    }
+    cache[i] = Some(ans);
+    ans
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((patterns, designs): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0;
+        for design in designs {
+            let mut cache = vec![None; design.len()];
+            if min_patterns_for_design(design, patterns, 0, &mut cache) > 0 {
+                ans += 1;
+            }
+        }
+        Ok(ans.to_string())
+    }
+
+    fn part2((patterns, designs): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0;
+        for design in designs {
+            let mut cache = vec![None; design.len()];
+            ans += min_patterns_for_design(design, patterns, 0, &mut cache);
+        }
+        Ok(ans.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("6", Day19::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("16", Day19::solve_part2(INPUT).unwrap());
+    }
+}
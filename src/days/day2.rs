@@ -1,11 +1,11 @@
-use advent_of_code_2024::read_input;
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
 
-fn parse_input(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines(input)
         .map(|line| {
-            line.split_whitespace()
-                .map(|x| x.parse().unwrap())
+            line.text
+                .split_whitespace()
+                .map(|x| line.parse(x))
                 .collect()
         })
         .collect()
@@ -43,15 +43,6 @@ fn is_safe_p1(report: &[i32]) -> bool {
     true
 }
 
-fn part1(input: &str) -> String {
-    let input = parse_input(input);
-    input
-        .into_iter()
-        .filter(|v| is_safe_p1(v))
-        .count()
-        .to_string()
-}
-
 fn is_safe_p2(report: &[i32]) -> bool {
     if is_safe_p1(report) {
         return true;
@@ -66,23 +57,25 @@ fn is_safe_p2(report: &[i32]) -> bool {
     false
 }
 
-// TODO - Optimise to O(N)
-fn part2(input: &str) -> String {
-    let input = parse_input(input);
-    input
-        .into_iter()
-        .filter(|v| is_safe_p2(v))
-        .count()
-        .to_string()
-}
+pub struct Day2;
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_input("day2")?;
-    println!("Part 1 solution:\n{}", part1(&input));
-    println!();
-    println!("Part 2 solution:\n{}", part2(&input));
+impl Solution for Day2 {
+    const DAY: u8 = 2;
 
-    Ok(())
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(reports: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(reports.iter().filter(|v| is_safe_p1(v)).count().to_string())
+    }
+
+    // TODO - Optimise to O(N)
+    fn part2(reports: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(reports.iter().filter(|v| is_safe_p2(v)).count().to_string())
+    }
 }
 
 #[cfg(test)]
@@ -97,7 +90,7 @@ mod tests {
 1 3 2 4 5
 8 6 4 4 1
 1 3 6 7 9";
-        assert_eq!("2", part1(input));
+        assert_eq!("2", Day2::solve_part1(input).unwrap());
     }
 
     #[test]
@@ -108,6 +101,6 @@ mod tests {
 1 3 2 4 5
 8 6 4 4 1
 1 3 6 7 9";
-        assert_eq!("4", part2(input));
+        assert_eq!("4", Day2::solve_part2(input).unwrap());
     }
 }
@@ -0,0 +1,125 @@
+use crate::{search, Answer, Grid, ParseError, Point, Solution, Unsolvable, Vector};
+use itertools::Itertools;
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::parse_with(input, |b| b"#.SE".contains(&b).then_some(b))?;
+    for marker in [b'S', b'E'] {
+        if grid.find(&marker).is_none() {
+            return Err(ParseError::new(format!(
+                "cannot find `{}` in the racetrack",
+                marker as char
+            )));
+        }
+    }
+    Ok(grid)
+}
+
+fn time_to(grid: &Grid<u8>, end: Point) -> Grid<u64> {
+    let max_cost: u64 = (grid.width() * grid.height() * 100) as u64;
+    let mut time = Grid::filled(grid.width(), grid.height(), max_cost);
+    let open = |&p: &Point| grid.neighbours4(p).filter(|&next| grid[next] != b'#');
+    for (p, sec) in search::bfs_distances(end, open) {
+        time[p] = sec as u64;
+    }
+    time
+}
+
+/*
+cheat duration = 3
+#############
+######3######
+#####323#####
+####32123####
+###321O123###
+####32123####
+#####323#####
+######3######
+#############
+
+For each point (x,y) within distance of "ps" from centre (r,c) where r in 0..H and c in 0..W,
+    If both (x,y) and (r,c) are valid and unblocked points,
+        Then cost_saved = time_to_end[r][c] - dis_between(x_y,r_c) - time_to_end[x][y]
+
+TC = O(HxWxPS^2)
+ */
+
+fn solve(grid: &Grid<u8>, time_to_end: Grid<u64>, ps: usize) -> u64 {
+    let min_time_saved = if !cfg!(test) { 100 } else { 50 };
+
+    let mut ans = 0;
+    let range = ps as isize;
+    // O(HW)
+    for p in grid.points() {
+        if grid[p] == b'#' {
+            continue;
+        }
+        // Costs O(2PS^2)
+        for (dx, dy) in (-range..=range).cartesian_product(-range..=range) {
+            let cheat = Vector::new(dx, dy);
+            if cheat.manhattan() > ps || cheat.manhattan() == 0 {
+                continue;
+            }
+
+            let Some(np) = grid.offset(p, cheat) else {
+                continue;
+            };
+            if grid[np] == b'#' {
+                continue;
+            }
+
+            let time_without_cheat = time_to_end[p];
+            let time_with_cheat = cheat.manhattan() as u64 + time_to_end[np];
+            if (time_without_cheat as i64) - (time_with_cheat as i64) >= min_time_saved {
+                ans += 1;
+            }
+        }
+    }
+    ans
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let time_to_end = time_to(grid, grid.find(&b'E').unwrap());
+        Ok(solve(grid, time_to_end, 2).to_string())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let time_to_end = time_to(grid, grid.find(&b'E').unwrap());
+        Ok(solve(grid, time_to_end, 20).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############";
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("285", Day20::solve_part2(INPUT).unwrap());
+    }
+}
@@ -1,14 +1,21 @@
-use std::{collections::HashMap, iter::repeat};
+use std::{collections::HashMap, iter::repeat_n};
 
-use advent_of_code_2024::read_input;
+use crate::{parse::lines, Answer, Direction, ParseError, Point, Solution, Unsolvable};
 use itertools::Itertools;
 
-fn parse_input(input: &str) -> Vec<Vec<u8>> {
-    input.lines().map(|l| l.as_bytes().to_vec()).collect()
+fn parse_input(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let digits = line
+                .text
+                .strip_suffix('A')
+                .ok_or_else(|| line.error("code must end with `A`"))?;
+            line.parse::<usize>(digits)?;
+            Ok(line.text.as_bytes().to_vec())
+        })
+        .collect()
 }
 
-type Point = (usize, usize);
-
 #[derive(Clone, Copy, Debug)]
 enum Keypad {
     Directional,
@@ -27,14 +34,14 @@ impl Keypad {
 
     fn start_pos(&self) -> Point {
         match self {
-            Keypad::Directional => (0, 2),
-            Keypad::Numeric => (3, 2),
+            Keypad::Directional => Point::new(0, 2),
+            Keypad::Numeric => Point::new(3, 2),
         }
     }
     fn invalid_pos(&self) -> Point {
         match self {
-            Keypad::Directional => (0, 0),
-            Keypad::Numeric => (3, 0),
+            Keypad::Directional => Point::new(0, 0),
+            Keypad::Numeric => Point::new(3, 0),
         }
     }
     fn array(&self) -> &[[u8; 3]] {
@@ -48,17 +55,11 @@ impl Keypad {
         for (i, row) in keypad.iter().enumerate() {
             for (j, ch) in row.iter().enumerate() {
                 if search_val == *ch {
-                    return (i, j);
+                    return Point::new(i, j);
                 }
             }
         }
-        (0, 0)
-    }
-    fn get_dir(ch: u8) -> (i64, i64) {
-        let chars = [b'>', b'<', b'^', b'v'];
-        let dirs = [(0, 1), (0, -1), (-1, 0), (1, 0)];
-        let i = chars.iter().position(|x| *x == ch).unwrap();
-        dirs[i]
+        Point::new(0, 0)
     }
 }
 
@@ -66,11 +67,8 @@ fn gen_path(start: Point, end: Point, keypad: Keypad) -> Vec<Vec<u8>> {
     fn is_invalid_code(code: &[u8], start: Point, keypad: Keypad) -> bool {
         let mut pos = start;
         for &next_ch in code {
-            let dir = Keypad::get_dir(next_ch);
-            pos = (
-                (pos.0 as i64 + dir.0) as usize,
-                (pos.1 as i64 + dir.1) as usize,
-            );
+            let dir = Direction::from_char(next_ch as char).unwrap();
+            pos = pos.checked_add(dir.vector()).unwrap();
             if keypad.invalid_pos() == pos {
                 return false;
             }
@@ -78,14 +76,15 @@ fn gen_path(start: Point, end: Point, keypad: Keypad) -> Vec<Vec<u8>> {
         true
     }
 
-    let (r_diff, c_diff) = (end.0 as i64 - start.0 as i64, end.1 as i64 - start.1 as i64);
+    let diff = end - start;
+    let (r_diff, c_diff) = (diff.dr, diff.dc);
 
     let mut chars = Vec::new();
 
-    chars.extend(repeat(b'v').take(r_diff.max(0) as usize));
-    chars.extend(repeat(b'^').take((-r_diff).max(0) as usize));
-    chars.extend(repeat(b'>').take(c_diff.max(0) as usize));
-    chars.extend(repeat(b'<').take((-c_diff).max(0) as usize));
+    chars.extend(repeat_n(b'v', r_diff.max(0) as usize));
+    chars.extend(repeat_n(b'^', (-r_diff).max(0) as usize));
+    chars.extend(repeat_n(b'>', c_diff.max(0) as usize));
+    chars.extend(repeat_n(b'<', (-c_diff).max(0) as usize));
 
     let len = chars.len();
     chars
@@ -136,39 +135,40 @@ fn solve(keypad: Keypad, code: &[u8], times: u64, cache: &mut HashMap<(String, u
     cost
 }
 
-fn part1(input: &str) -> String {
-    let codes = parse_input(input);
-    let mut ans = 0usize;
-    let mut cache = HashMap::new();
-    for code in codes {
-        let val = solve(Keypad::Numeric, &code, 2, &mut cache);
-        let code_str = code.iter().map(|x| *x as char).collect::<String>();
-        let code_int: usize = code_str[..code_str.len() - 1].parse().unwrap();
-        ans += val as usize * code_int;
-    }
-    ans.to_string()
-}
+pub struct Day21;
 
-fn part2(input: &str) -> String {
-    let codes = parse_input(input);
-    let mut ans = 0usize;
-    let mut cache = HashMap::new();
-    for code in codes {
-        let val = solve(Keypad::Numeric, &code, 25, &mut cache);
-        let code_str = code.iter().map(|x| *x as char).collect::<String>();
-        let code_int: usize = code_str[..code_str.len() - 1].parse().unwrap();
-        ans += val as usize * code_int;
-    }
-    ans.to_string()
-}
+impl Solution for Day21 {
+    const DAY: u8 = 21;
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_input("day21")?;
-    println!("{}", part1(&input));
+    type Parsed = Vec<Vec<u8>>;
 
-    println!("{}", part2(&input));
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(codes: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0usize;
+        let mut cache = HashMap::new();
+        for code in codes {
+            let val = solve(Keypad::Numeric, code, 2, &mut cache);
+            let code_str = code.iter().map(|x| *x as char).collect::<String>();
+            let code_int: usize = code_str[..code_str.len() - 1].parse().unwrap();
+            ans += val as usize * code_int;
+        }
+        Ok(ans.to_string())
+    }
 
-    Ok(())
+    fn part2(codes: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut ans = 0usize;
+        let mut cache = HashMap::new();
+        for code in codes {
+            let val = solve(Keypad::Numeric, code, 25, &mut cache);
+            let code_str = code.iter().map(|x| *x as char).collect::<String>();
+            let code_int: usize = code_str[..code_str.len() - 1].parse().unwrap();
+            ans += val as usize * code_int;
+        }
+        Ok(ans.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -182,7 +182,7 @@ mod tests {
 179A
 456A
 379A";
-        assert_eq!("126384", part1(input));
+        assert_eq!("126384", Day21::solve_part1(input).unwrap());
     }
 
     #[test]
@@ -190,6 +190,6 @@ mod tests {
     fn test_part2() {
         let input = "";
 
-        assert_eq!("", part2(input));
+        assert_eq!("", Day21::solve_part2(input).unwrap());
     }
 }
@@ -1,9 +1,9 @@
-use std::{collections::VecDeque, time::Instant};
+use std::collections::VecDeque;
 
-use advent_of_code_2024::read_input;
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
 
-fn parse_input(input: &str) -> Vec<u64> {
-    input.lines().map(|n| n.parse().unwrap()).collect()
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    lines(input).map(|line| line.parse(line.text)).collect()
 }
 
 /*
@@ -19,13 +19,6 @@ fn next_secret(num: u64) -> u64 {
     n = ((n * 2048) ^ n) % 16777216;
     n
 }
-fn part1(input: &str) -> String {
-    parse_input(input)
-        .into_iter()
-        .map(|num| (0..2000).fold(num, |acc, _| next_secret(acc)))
-        .sum::<u64>()
-        .to_string()
-}
 
 fn solve_2(nums: &[u64]) -> u64 {
     let hash = |a, b, c, d| {
@@ -64,22 +57,28 @@ fn solve_2(nums: &[u64]) -> u64 {
     map.into_iter().max().unwrap()
 }
 
-fn part2(input: &str) -> String {
-    solve_2(&parse_input(input)).to_string()
-}
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_input("day22")?;
+    type Parsed = Vec<u64>;
 
-    let before = Instant::now();
-    println!("{}", part1(&input));
-    println!("Time taken - {:?}", Instant::now() - before);
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
 
-    let before = Instant::now();
-    println!("{}", part2(&input));
-    println!("Time taken - {:?}", Instant::now() - before);
+    fn part1(secrets: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(secrets
+            .iter()
+            .map(|&num| (0..2000).fold(num, |acc, _| next_secret(acc)))
+            .sum::<u64>()
+            .to_string())
+    }
 
-    Ok(())
+    fn part2(secrets: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(solve_2(secrets).to_string())
+    }
 }
 
 #[cfg(test)]
@@ -92,7 +91,7 @@ mod tests {
 10
 100
 2024";
-        assert_eq!("37327623", part1(input));
+        assert_eq!("37327623", Day22::solve_part1(input).unwrap());
     }
 
     #[test]
@@ -102,6 +101,6 @@ mod tests {
 3
 2024";
 
-        assert_eq!("23", part2(input));
+        assert_eq!("23", Day22::solve_part2(input).unwrap());
     }
 }
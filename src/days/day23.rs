@@ -0,0 +1,147 @@
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+use itertools::Itertools;
+
+const MAX_NODES: usize = 26 * 26;
+
+fn parse_input(input: &str) -> Result<Vec<(u32, u32)>, ParseError> {
+    let connections = lines(input)
+        .map(|line| {
+            let id = |id_str: &str| match id_str.as_bytes() {
+                [a @ b'a'..=b'z', b @ b'a'..=b'z'] => {
+                    Ok((a - b'a') as u32 * 26 + (b - b'a') as u32)
+                }
+                _ => Err(line.error(format!("invalid computer name `{id_str}`"))),
+            };
+            let (left, right) = line.split_once("-")?;
+            let (left, right) = (id(left)?, id(right)?);
+            if left < right {
+                Ok((left, right))
+            } else {
+                Ok((right, left))
+            }
+        })
+        .collect::<Result<Vec<(u32, u32)>, ParseError>>()?;
+    if connections.is_empty() {
+        return Err(ParseError::new("no connections"));
+    }
+    Ok(connections)
+}
+
+fn to_string(node: &u32) -> String {
+    (((node / 26) as u8 + b'a') as char).to_string()
+        + &(((node % 26) as u8 + b'a') as char).to_string()
+}
+
+fn should_connect(edges: &[Vec<bool>], connected_component: &[u32], new_node: u32) -> bool {
+    connected_component.iter().all(|connected_node| {
+        new_node != *connected_node && edges[*connected_node as usize][new_node as usize]
+    })
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Parsed = Vec<(u32, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(connections: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let starts_with_t = |n: u32| (n / 26) as u8 == b't' - b'a';
+        let mut edges = vec![vec![false; MAX_NODES]; MAX_NODES];
+        for edge in connections.iter() {
+            edges[edge.0 as usize][edge.1 as usize] = true;
+        }
+        let mut ans = 0;
+        for connection in connections {
+            let (edges_0, edges_1) = (&edges[connection.0 as usize], &edges[connection.1 as usize]);
+            for node_3 in (connection.1 as usize)..(MAX_NODES) {
+                if edges_0[node_3]
+                    && edges_1[node_3]
+                    && (starts_with_t(node_3 as u32)
+                        || starts_with_t(connection.0)
+                        || starts_with_t(connection.1))
+                {
+                    ans += 1;
+                }
+            }
+        }
+        Ok(ans.to_string())
+    }
+
+    // Optimisation inspired by - https://www.reddit.com/r/adventofcode/comments/1hkgj5b/2024_day_23_solutions/m3fbw3t/
+    fn part2(connections: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut edges = vec![vec![false; MAX_NODES]; MAX_NODES];
+        for edge in connections.iter() {
+            edges[edge.0 as usize][edge.1 as usize] = true;
+        }
+
+        let mut interconnections: Vec<Vec<u32>> =
+            connections.iter().map(|&c| vec![c.0, c.1]).collect();
+
+        for connection in interconnections.iter_mut() {
+            for node in *connection.last().unwrap()..(MAX_NODES as u32) {
+                if should_connect(&edges, connection, node) {
+                    connection.push(node);
+                }
+            }
+        }
+        Ok(interconnections
+            .iter()
+            .max_by(|a, b| a.len().cmp(&b.len()))
+            .unwrap()
+            .iter()
+            .map(to_string)
+            .join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("7", Day23::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("co,de,ka,ta", Day23::solve_part2(INPUT).unwrap());
+    }
+}
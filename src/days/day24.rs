@@ -0,0 +1,540 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{
+    parse::{expect_sections, Line},
+    Answer, ParseError, Solution, Unsolvable,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::And => a && b,
+            Op::Or => a || b,
+            Op::Xor => a ^ b,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gate {
+    pub inputs: [String; 2],
+    pub op: Op,
+    pub output: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    pub wires: Vec<(String, bool)>,
+    pub gates: Vec<Gate>,
+}
+
+fn parse_wire(line: &Line) -> Result<(String, bool), ParseError> {
+    let (name, value) = line.split_once(": ")?;
+    match value.trim() {
+        "0" => Ok((name.to_string(), false)),
+        "1" => Ok((name.to_string(), true)),
+        value => Err(line.error(format!("expected 0 or 1, found `{value}`"))),
+    }
+}
+
+fn parse_gate(line: &Line) -> Result<Gate, ParseError> {
+    let (inputs, output) = line.split_once(" -> ")?;
+    let [a, op, b] = inputs.split_whitespace().collect::<Vec<_>>()[..] else {
+        return Err(line.error("expected `WIRE OP WIRE -> WIRE`"));
+    };
+    let op = match op {
+        "AND" => Op::And,
+        "OR" => Op::Or,
+        "XOR" => Op::Xor,
+        op => return Err(line.error(format!("unknown gate `{op}`"))),
+    };
+    Ok(Gate {
+        inputs: [a.to_string(), b.to_string()],
+        op,
+        output: output.trim().to_string(),
+    })
+}
+
+fn parse_input(input: &str) -> Result<Device, ParseError> {
+    let [wires, gates] = expect_sections(input, ["wire values", "gates"])?;
+    let wires = wires
+        .iter()
+        .map(parse_wire)
+        .collect::<Result<Vec<_>, _>>()?;
+    let gates = gates
+        .iter()
+        .map(parse_gate)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut drivers: HashMap<&str, usize> = HashMap::new();
+    for name in wires
+        .iter()
+        .map(|(name, _)| name)
+        .chain(gates.iter().map(|g| &g.output))
+    {
+        *drivers.entry(name).or_default() += 1;
+    }
+    if let Some((name, _)) = drivers.iter().find(|(_, &count)| count > 1) {
+        return Err(ParseError::new(format!(
+            "wire `{name}` is set more than once"
+        )));
+    }
+    Ok(Device { wires, gates })
+}
+
+/// Evaluates the gates in topological order, starting from the given wire values.
+fn evaluate<'a>(
+    gates: &'a [Gate],
+    wires: impl IntoIterator<Item = (&'a str, bool)>,
+) -> Result<HashMap<&'a str, bool>, Unsolvable> {
+    let mut values: HashMap<&str, bool> = wires.into_iter().collect();
+    let mut consumers: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut missing = vec![0; gates.len()];
+    for (i, gate) in gates.iter().enumerate() {
+        for input in &gate.inputs {
+            consumers.entry(input).or_default().push(i);
+            if !values.contains_key(input.as_str()) {
+                missing[i] += 1;
+            }
+        }
+    }
+
+    let mut ready: VecDeque<usize> = (0..gates.len()).filter(|&i| missing[i] == 0).collect();
+    let mut evaluated = 0;
+    while let Some(i) = ready.pop_front() {
+        let gate = &gates[i];
+        let [a, b] = &gate.inputs;
+        let value = gate.op.apply(values[a.as_str()], values[b.as_str()]);
+        values.insert(&gate.output, value);
+        evaluated += 1;
+        for &consumer in consumers.get(gate.output.as_str()).into_iter().flatten() {
+            missing[consumer] -= 1;
+            if missing[consumer] == 0 {
+                ready.push_back(consumer);
+            }
+        }
+    }
+
+    if evaluated < gates.len() {
+        let stuck = gates.iter().zip(&missing).find(|(_, &m)| m > 0).unwrap().0;
+        return Err(Unsolvable::new(format!(
+            "gate `{}` never gets all its inputs, they are unset or form a loop",
+            stuck.output
+        )));
+    }
+    Ok(values)
+}
+
+/// Wires starting with `prefix` (`x`, `y` or `z`), most significant bit last.
+fn bus<'a>(names: impl IntoIterator<Item = &'a str>, prefix: char) -> Vec<&'a str> {
+    let mut bus: Vec<&str> = names
+        .into_iter()
+        .filter(|name| name.starts_with(prefix))
+        .collect();
+    bus.sort();
+    bus
+}
+
+fn number(values: &HashMap<&str, bool>, prefix: char) -> u64 {
+    bus(values.keys().copied(), prefix)
+        .iter()
+        .rev()
+        .fold(0, |n, wire| n << 1 | values[wire] as u64)
+}
+
+fn other_input(inputs: &[String; 2], input: &str) -> Option<String> {
+    match inputs {
+        [a, b] if a == input => Some(b.clone()),
+        [a, b] if b == input => Some(a.clone()),
+        _ => None,
+    }
+}
+
+fn wire(prefix: char, bit: usize) -> String {
+    format!("{prefix}{bit:02}")
+}
+
+/// The gates of a device being repaired, with the gate driving each wire and the
+/// gate combining each pair of inputs.
+struct Adder {
+    gates: Vec<Gate>,
+    drivers: HashMap<String, usize>,
+    by_inputs: HashMap<(Op, String, String), usize>,
+    swaps: Vec<(String, String)>,
+}
+
+impl Adder {
+    fn new(gates: &[Gate]) -> Self {
+        let key = |gate: &Gate| {
+            let [a, b] = &gate.inputs;
+            (gate.op, a.min(b).clone(), a.max(b).clone())
+        };
+        Adder {
+            gates: gates.to_vec(),
+            drivers: (gates.iter().enumerate())
+                .map(|(i, gate)| (gate.output.clone(), i))
+                .collect(),
+            by_inputs: (gates.iter().enumerate())
+                .map(|(i, gate)| (key(gate), i))
+                .collect(),
+            swaps: Vec::new(),
+        }
+    }
+
+    /// The output of the gate combining `a` and `b` with `op`.
+    fn find(&self, op: Op, a: &str, b: &str) -> Option<String> {
+        let key = (op, a.min(b).to_string(), a.max(b).to_string());
+        self.by_inputs
+            .get(&key)
+            .map(|&i| self.gates[i].output.clone())
+    }
+
+    /// The inputs of the gate driving `output`, if it is an `op` gate.
+    fn inputs(&self, op: Op, output: &str) -> Option<&[String; 2]> {
+        let gate = &self.gates[*self.drivers.get(output)?];
+        (gate.op == op).then_some(&gate.inputs)
+    }
+
+    /// The other input of some `op` gate taking `input`.
+    fn partner(&self, op: Op, input: &str) -> Option<String> {
+        self.gates
+            .iter()
+            .filter(|gate| gate.op == op)
+            .find_map(|gate| other_input(&gate.inputs, input))
+    }
+
+    /// The other input of the `op` gates taking `input`, with their outputs.
+    fn partners(&self, op: Op, input: &str) -> Vec<(String, String)> {
+        self.gates
+            .iter()
+            .filter(|gate| gate.op == op)
+            .filter_map(|gate| Some((other_input(&gate.inputs, input)?, gate.output.clone())))
+            .collect()
+    }
+
+    fn swap(&mut self, a: &str, b: &str) -> Result<(), Unsolvable> {
+        let (Some(&i), Some(&j)) = (self.drivers.get(a), self.drivers.get(b)) else {
+            return Err(Unsolvable::new(format!("no gates drive `{a}` and `{b}`")));
+        };
+        self.gates[i].output = b.to_string();
+        self.gates[j].output = a.to_string();
+        self.drivers.insert(a.to_string(), j);
+        self.drivers.insert(b.to_string(), i);
+        self.swaps.push((a.to_string(), b.to_string()));
+        Ok(())
+    }
+}
+
+fn missing_gate(bit: usize, what: &str) -> Unsolvable {
+    Unsolvable::new(format!(
+        "bit {bit} has no {what} gate, this isn't a ripple-carry adder"
+    ))
+}
+
+/// Checks the full adder for `bit`, returning the carry out of it, or `None` after
+/// swapping a pair of outputs to fix one of its gates. `carry` is the carry into it.
+fn check_bit(
+    adder: &mut Adder,
+    bit: usize,
+    carry: &mut String,
+) -> Result<Option<String>, Unsolvable> {
+    let (x, y, z) = (wire('x', bit), wire('y', bit), wire('z', bit));
+    let half = adder
+        .find(Op::Xor, &x, &y)
+        .ok_or_else(|| missing_gate(bit, "input XOR"))?;
+    let generate = adder
+        .find(Op::And, &x, &y)
+        .ok_or_else(|| missing_gate(bit, "input AND"))?;
+
+    let Some(sum) = adder.find(Op::Xor, &half, carry) else {
+        // One of the sum gate's inputs comes from the wrong wire. If zi is still driven
+        // by the sum gate, the input it shares with us is the right one.
+        let sum_inputs = adder.inputs(Op::Xor, &z);
+        let shared = |wire: &str| sum_inputs.and_then(|inputs| other_input(inputs, wire));
+        if let Some(other) = shared(&half).or_else(|| adder.partner(Op::Xor, &half)) {
+            adder.swap(carry, &other)?;
+            *carry = other;
+        } else if let Some(other) = shared(carry).or_else(|| adder.partner(Op::Xor, carry)) {
+            adder.swap(&half, &other)?;
+        } else {
+            return Err(missing_gate(bit, "sum"));
+        }
+        return Ok(None);
+    };
+    if sum != z {
+        adder.swap(&sum, &z)?;
+        return Ok(None);
+    }
+
+    let propagate = adder
+        .find(Op::And, &half, carry)
+        .ok_or_else(|| missing_gate(bit, "carry AND"))?;
+    let Some(carry_out) = adder.find(Op::Or, &generate, &propagate) else {
+        // The carry gate takes one of them and some other wire in place of the other.
+        // Prefer the OR gate whose output goes on to the next bit's sum gate.
+        let next_half = adder.find(Op::Xor, &wire('x', bit + 1), &wire('y', bit + 1));
+        let carries_on = |output: &str| match &next_half {
+            Some(next_half) => adder.find(Op::Xor, next_half, output).is_some(),
+            None => output == wire('z', bit + 1),
+        };
+        let mut candidates: Vec<(String, String, String)> = (adder.partners(Op::Or, &generate))
+            .into_iter()
+            .map(|(other, output)| (propagate.clone(), other, output))
+            .chain(
+                (adder.partners(Op::Or, &propagate))
+                    .into_iter()
+                    .map(|(other, output)| (generate.clone(), other, output)),
+            )
+            .collect();
+        candidates.sort_by_key(|(_, _, output)| !carries_on(output));
+        let Some((wrong, other, _)) = candidates.into_iter().next() else {
+            return Err(missing_gate(bit, "carry OR"));
+        };
+        adder.swap(&wrong, &other)?;
+        return Ok(None);
+    };
+    Ok(Some(carry_out))
+}
+
+/// Walks the adder from the lowest bit up, expecting for bit `i` the gates
+///
+/// ```text
+/// xi XOR yi -> half         xi AND yi -> generate
+/// half XOR carry -> zi      half AND carry -> propagate
+/// generate OR propagate -> next carry
+/// ```
+///
+/// with a plain half adder for bit 0 and the last carry being the top `z` bit. Where
+/// a gate's output is the wrong wire, the pair of outputs that fixes it is swapped and
+/// the bit checked again. Returns the swapped pairs in the order they were found.
+pub fn find_swaps(device: &Device) -> Result<Vec<(String, String)>, Unsolvable> {
+    let names = device.wires.iter().map(|(name, _)| name.as_str());
+    let bits = bus(names.clone(), 'x').len();
+    if bits == 0 || bits >= 64 || bus(names, 'y').len() != bits {
+        return Err(Unsolvable::new(
+            "an adder needs x and y inputs of the same width, below 64 bits",
+        ));
+    }
+    let mut adder = Adder::new(&device.gates);
+
+    let (x, y, z) = (wire('x', 0), wire('y', 0), wire('z', 0));
+    let sum = adder
+        .find(Op::Xor, &x, &y)
+        .ok_or_else(|| missing_gate(0, "sum"))?;
+    if sum != z {
+        adder.swap(&sum, &z)?;
+    }
+    let mut carry = adder
+        .find(Op::And, &x, &y)
+        .ok_or_else(|| missing_gate(0, "carry"))?;
+
+    for bit in 1..bits {
+        // Each swap fixes one of the bit's gates, so a few are enough.
+        let mut attempts = 0;
+        carry = loop {
+            if let Some(carry_out) = check_bit(&mut adder, bit, &mut carry)? {
+                break carry_out;
+            }
+            attempts += 1;
+            if attempts == 4 {
+                return Err(Unsolvable::new(format!(
+                    "bit {bit} is still wrong after {attempts} swaps"
+                )));
+            }
+        };
+    }
+
+    let top = wire('z', bits);
+    if carry != top {
+        adder.swap(&carry, &top)?;
+    }
+    check_sums(&adder.gates, bits)?;
+    Ok(adder.swaps)
+}
+
+/// Runs additions that exercise every bit and carry through the repaired gates.
+fn check_sums(gates: &[Gate], bits: usize) -> Result<(), Unsolvable> {
+    let mask = (1 << bits) - 1;
+    let mut cases = vec![
+        (0, 0),
+        (mask, 1),
+        (mask, mask),
+        (0x5555_5555_5555 & mask, 0x3333_3333_3333 & mask),
+    ];
+    cases.extend((0..bits).map(|bit| (1 << bit, 1 << bit)));
+
+    let names: Vec<(String, String)> = (0..bits)
+        .map(|bit| (wire('x', bit), wire('y', bit)))
+        .collect();
+    for (x, y) in cases {
+        let inputs = names
+            .iter()
+            .enumerate()
+            .flat_map(|(bit, (x_wire, y_wire))| {
+                [
+                    (x_wire.as_str(), x >> bit & 1 == 1),
+                    (y_wire.as_str(), y >> bit & 1 == 1),
+                ]
+            });
+        let sum = number(&evaluate(gates, inputs)?, 'z');
+        if sum != x + y {
+            return Err(Unsolvable::new(format!(
+                "the repaired adder gives {x} + {y} = {sum}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Parsed = Device;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(device: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let wires = device
+            .wires
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value));
+        let values = evaluate(&device.gates, wires)?;
+        Ok(number(&values, 'z').to_string())
+    }
+
+    fn part2(device: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut wires: Vec<String> = find_swaps(device)?
+            .into_iter()
+            .flat_map(|(a, b)| [a, b])
+            .collect();
+        wires.sort();
+        Ok(wires.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj";
+        assert_eq!("2024", Day24::solve_part1(input).unwrap());
+    }
+
+    /// A 4-bit ripple-carry adder with `swaps` applied to its gate outputs.
+    fn adder(swaps: &[(&str, &str)]) -> String {
+        let mut lines = vec!["x00: 1".to_string(), "y00: 1".to_string()];
+        for bit in 1..4 {
+            lines.push(format!("x{bit:02}: 0\ny{bit:02}: 1"));
+        }
+        lines.push(String::new());
+        let mut gates = vec![
+            ("x00 XOR y00", "z00".to_string()),
+            ("x00 AND y00", "c00".to_string()),
+        ];
+        let owned: Vec<(String, String)> = (1..4)
+            .flat_map(|bit| {
+                let c = format!("c{:02}", bit - 1);
+                let next = if bit == 3 {
+                    "z04".to_string()
+                } else {
+                    format!("c{bit:02}")
+                };
+                [
+                    (format!("x{bit:02} XOR y{bit:02}"), format!("s{bit:02}")),
+                    (format!("x{bit:02} AND y{bit:02}"), format!("a{bit:02}")),
+                    (format!("s{bit:02} XOR {c}"), format!("z{bit:02}")),
+                    (format!("s{bit:02} AND {c}"), format!("b{bit:02}")),
+                    (format!("a{bit:02} OR b{bit:02}"), next),
+                ]
+            })
+            .collect();
+        gates.extend(
+            owned
+                .iter()
+                .map(|(inputs, output)| (inputs.as_str(), output.clone())),
+        );
+        for (inputs, output) in &mut gates {
+            for &(a, b) in swaps {
+                if output == a {
+                    *output = b.to_string();
+                } else if output == b {
+                    *output = a.to_string();
+                }
+            }
+            lines.push(format!("{inputs} -> {output}"));
+        }
+        lines.join("\n")
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("", Day24::solve_part2(&adder(&[])).unwrap());
+        // 1 + 15
+        assert_eq!("16", Day24::solve_part1(&adder(&[])).unwrap());
+
+        let broken = adder(&[("z02", "b02"), ("s03", "a03")]);
+        let device = Day24::parse(&broken).unwrap();
+        let swaps = find_swaps(&device).unwrap();
+        assert_eq!(2, swaps.len());
+        assert_eq!("a03,b02,s03,z02", Day24::solve_part2(&broken).unwrap());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    parse::{sections, Line},
+    Answer, ParseError, Solution, Unsolvable,
+};
+
+const WIDTH: usize = 5;
+const HEIGHT: usize = 7;
+/// The most pins that fit between a lock's top row and a key's bottom row.
+const SPACE: u8 = HEIGHT as u8 - 2;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Schematics {
+    /// Pin heights of each lock, counted down from the top row.
+    pub locks: Vec<[u8; WIDTH]>,
+    /// Heights of each key, counted up from the bottom row.
+    pub keys: Vec<[u8; WIDTH]>,
+}
+
+/// Heights of the `#` columns below the first row, which is solid for both locks and
+/// keys (keys are read upside down). Each column must be a solid run from the top.
+fn heights(rows: &[Line]) -> Result<[u8; WIDTH], ParseError> {
+    let mut heights = [0; WIDTH];
+    for (column, height) in heights.iter_mut().enumerate() {
+        let filled = rows
+            .iter()
+            .take_while(|row| row.text.as_bytes()[column] == b'#')
+            .count();
+        if let Some(row) = rows[filled..]
+            .iter()
+            .find(|row| row.text.as_bytes()[column] == b'#')
+        {
+            return Err(row.error(format!("column {} has a gap in it", column + 1)));
+        }
+        *height = filled as u8 - 1;
+    }
+    Ok(heights)
+}
+
+fn parse_input(input: &str) -> Result<Schematics, ParseError> {
+    let mut schematics = Schematics::default();
+    for rows in sections(input) {
+        if rows.len() != HEIGHT {
+            return Err(rows[0].error(format!(
+                "expected a schematic of {HEIGHT} rows, found {}",
+                rows.len()
+            )));
+        }
+        let rows: Vec<Line> = rows
+            .into_iter()
+            .map(|row| Line {
+                text: row.text.trim(),
+                ..row
+            })
+            .collect();
+        for row in &rows {
+            row.expect_chars("#.")?;
+            if row.text.len() != WIDTH {
+                return Err(row.error(format!(
+                    "expected {WIDTH} columns, found {}",
+                    row.text.len()
+                )));
+            }
+        }
+
+        let solid = |row: &Line| row.text.bytes().all(|b| b == b'#');
+        if solid(&rows[0]) {
+            schematics.locks.push(heights(&rows)?);
+        } else if solid(&rows[HEIGHT - 1]) {
+            let upside_down: Vec<Line> = rows.into_iter().rev().collect();
+            schematics.keys.push(heights(&upside_down)?);
+        } else {
+            return Err(rows[0].error("neither the top nor the bottom row is solid"));
+        }
+    }
+    Ok(schematics)
+}
+
+fn fits(lock: &[u8; WIDTH], key: &[u8; WIDTH]) -> bool {
+    lock.iter().zip(key).all(|(l, k)| l + k <= SPACE)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Parsed = Schematics;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(input
+            .locks
+            .iter()
+            .map(|lock| input.keys.iter().filter(|key| fits(lock, key)).count())
+            .sum::<usize>()
+            .to_string())
+    }
+
+    // There is no second puzzle on the last day, the star comes with the other 49.
+    fn part2(_: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(String::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+    #[test]
+    fn test_part1() {
+        let schematics = Day25::parse(INPUT).unwrap();
+        assert_eq!(vec![[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]], schematics.locks);
+        assert_eq!(
+            vec![[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]],
+            schematics.keys
+        );
+        assert_eq!("3", Day25::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| Day25::parse(input).unwrap_err().to_string();
+        assert!(error(&INPUT.replacen(".####", "#.###", 1)).contains("has a gap"));
+        assert!(error(&INPUT.replacen("#####", "####.", 1)).contains("neither"));
+        assert!(error(&INPUT[..20]).contains("expected a schematic of 7 rows"));
+    }
+}
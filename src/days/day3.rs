@@ -1,8 +1,8 @@
-use advent_of_code_2024::read_input;
+use crate::{Answer, ParseError, Solution, Unsolvable};
 use regex::Regex;
 
-fn parse_input(input: &str) -> Vec<String> {
-    input.lines().map(String::from).collect()
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(String::from).collect())
 }
 
 fn get_valid_instructions_1(corr_memory: &str) -> Vec<(i64, i64)> {
@@ -16,15 +16,6 @@ fn get_valid_instructions_1(corr_memory: &str) -> Vec<(i64, i64)> {
         .collect()
 }
 
-fn part1(input: &str) -> String {
-    let corr_memories = parse_input(input);
-    get_valid_instructions_1(&corr_memories.join(""))
-        .iter()
-        .map(|(a, b)| a * b)
-        .sum::<i64>()
-        .to_string()
-}
-
 fn get_valid_instructions_2(corr_memory: &str) -> Vec<(i64, i64)> {
     let re = Regex::new("(?:mul\\(([0-9]{1,3}),([0-9]{1,3})\\)|do\\(\\)|don't\\(\\))").unwrap();
     let mut flag = true;
@@ -49,22 +40,32 @@ fn get_valid_instructions_2(corr_memory: &str) -> Vec<(i64, i64)> {
         .collect()
 }
 
-fn part2(input: &str) -> String {
-    let corr_memories = parse_input(input);
-    get_valid_instructions_2(&corr_memories.join(""))
-        .iter()
-        .map(|(a, b)| a * b)
-        .sum::<i64>()
-        .to_string()
-}
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_input("day3")?;
-    println!("{}", part1(&input));
+    type Parsed = Vec<String>;
 
-    println!("{}", part2(&input));
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(corr_memories: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(get_valid_instructions_1(&corr_memories.join(""))
+            .iter()
+            .map(|(a, b)| a * b)
+            .sum::<i64>()
+            .to_string())
+    }
 
-    Ok(())
+    fn part2(corr_memories: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(get_valid_instructions_2(&corr_memories.join(""))
+            .iter()
+            .map(|(a, b)| a * b)
+            .sum::<i64>()
+            .to_string())
+    }
 }
 
 #[cfg(test)]
@@ -74,7 +75,7 @@ mod tests {
     #[test]
     fn test_part1() {
         let input: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        assert_eq!("161", part1(input));
+        assert_eq!("161", Day3::solve_part1(input).unwrap());
     }
 
     #[test]
@@ -82,6 +83,6 @@ mod tests {
         let input: &str =
             "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
-        assert_eq!("48", part2(input));
+        assert_eq!("48", Day3::solve_part2(input).unwrap());
     }
 }
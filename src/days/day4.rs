@@ -0,0 +1,113 @@
+use crate::{Answer, Grid, ParseError, Point, Solution, Unsolvable, Vector};
+
+const D: [isize; 3] = [1, 0, -1];
+
+fn get_word_1(grid: &Grid<u8>, start: Point, dir: Vector) -> Option<String> {
+    let ans: String = (0..4)
+        .scan(Some(start), |pos, _| {
+            let res = (*pos)?;
+            *pos = grid.offset(res, dir);
+            Some(res)
+        })
+        .map(|p| grid[p] as char)
+        .collect();
+    if ans.len() == 4 {
+        Some(ans)
+    } else {
+        None
+    }
+}
+
+fn count_1(grid: &Grid<u8>) -> u32 {
+    let mut count = 0;
+    for p in grid.points() {
+        for di in D {
+            for dj in D {
+                if di == 0 && dj == 0 {
+                    continue;
+                }
+                if get_word_1(grid, p, Vector::new(di, dj)) == Some("XMAS".to_string()) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
+}
+
+fn count_2(grid: &Grid<u8>) -> u32 {
+    let at = |i, j| grid[Point::new(i, j)] as char;
+    let mut count = 0;
+    for i in 1..grid.height() - 1 {
+        for j in 1..grid.width() - 1 {
+            if at(i, j) == 'A' {
+                let ldiag = format!("{}{}{}", at(i - 1, j - 1), 'A', at(i + 1, j + 1));
+                let rdiag = format!("{}{}{}", at(i - 1, j + 1), 'A', at(i + 1, j - 1));
+                if (ldiag == "SAM" || ldiag == "MAS") && (rdiag == "SAM" || rdiag == "MAS") {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(count_1(grid).to_string())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(count_2(grid).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+        assert_eq!("18", Day4::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = ".M.S......
+..A..MSMS.
+.M.S.MAA..
+..A.ASMSM.
+.M.S.M....
+..........
+S.S.S.S.S.
+.A.A.A.A..
+M.M.M.M.M.
+..........";
+
+        assert_eq!("9", Day4::solve_part2(input).unwrap());
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Index,
+};
+
+use crate::{parse::expect_sections, Answer, ParseError, Solution, Unsolvable};
+
+type Parsed = (Vec<(u32, u32)>, Vec<Vec<u32>>);
+
+fn parse_input(input: &str) -> Result<Parsed, ParseError> {
+    let [rules, updates] = expect_sections(input, ["ordering rules", "updates"])?;
+    let order_vec: Vec<(u32, u32)> = rules
+        .iter()
+        .map(|line| {
+            let (l, r) = line.split_once("|")?;
+            Ok((line.parse(l)?, line.parse(r)?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let updates_vec: Vec<Vec<u32>> = updates
+        .iter()
+        .map(|line| line.text.split(",").map(|n| line.parse(n)).collect())
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((order_vec, updates_vec))
+}
+
+fn create_graph(order_rules: &[(u32, u32)], update: &[u32]) -> HashMap<u32, Vec<u32>> {
+    let mut graph: HashMap<u32, Vec<u32>> = HashMap::new();
+
+    let updates_set = update.iter().copied().collect::<HashSet<u32>>();
+
+    for (l, r) in order_rules {
+        if !updates_set.contains(l) || !updates_set.contains(r) {
+            continue;
+        }
+        graph.entry(*l).or_default().push(*r);
+    }
+    graph
+}
+
+fn traverse(
+    graph: &HashMap<u32, Vec<u32>>,
+    node: u32,
+    output: &mut Vec<u32>,
+    visited: &mut HashSet<u32>,
+) {
+    if visited.contains(&node) {
+        return;
+    }
+    for child_node in graph
+        .get(&node)
+        .into_iter()
+        .flatten()
+        .collect::<Vec<&u32>>()
+    {
+        traverse(graph, *child_node, output, visited);
+    }
+    visited.insert(node);
+    output.push(node);
+}
+
+fn get_topological_order(graph: HashMap<u32, Vec<u32>>) -> Vec<u32> {
+    let mut output = Vec::new();
+    let mut visited = HashSet::new();
+    for node in graph.keys() {
+        traverse(&graph, *node, &mut output, &mut visited);
+    }
+    output.reverse();
+    output
+}
+
+fn valid_update(update: &[u32], topo: &[u32]) -> bool {
+    let mut prev = 0;
+    update.iter().all(|node| {
+        let idx = topo.iter().position(|x| x == node).unwrap();
+        if idx < prev {
+            return false;
+        }
+        prev = idx;
+        true
+    })
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Parsed = Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((order_rules, updates): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(updates
+            .iter()
+            .map(|nodes| {
+                let graph = create_graph(order_rules, nodes);
+                let topo = get_topological_order(graph);
+                if valid_update(nodes, &topo) {
+                    return *nodes.index(nodes.len() / 2);
+                }
+                0
+            })
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part2((order_rules, updates): &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(updates
+            .iter()
+            .map(|nodes| {
+                let graph = create_graph(order_rules, nodes);
+                let topo = get_topological_order(graph);
+                if !valid_update(nodes, &topo) {
+                    return *topo.index(topo.len() / 2);
+                }
+                0
+            })
+            .sum::<u32>()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("143", Day5::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("123", Day5::solve_part2(INPUT).unwrap());
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{Answer, Direction, Grid, ParseError, Point, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    let map = Grid::parse_with(input, |b| b".#^".contains(&b).then_some(b))?;
+    if map.find(&b'^').is_none() {
+        return Err(ParseError::new("cannot find start point `^`"));
+    }
+    Ok(map)
+}
+
+// As per part 1, guard will always leave mapped area
+fn traverse_1(map: &mut Grid<u8>, start: Point) {
+    let mut pos = start;
+    let mut dir = Direction::Up;
+    loop {
+        map[pos] = b'X';
+        match map.step(pos, dir) {
+            None => return,
+            Some(next) if map[next] == b'#' => dir = dir.turn_right(),
+            Some(next) => pos = next,
+        }
+    }
+}
+
+fn guard_index(map: &Grid<u8>) -> Point {
+    map.find(&b'^').expect("Cannnot find start point")
+}
+
+fn visited_2(cache: &Grid<u32>, pos: Point, dir: Direction) -> bool {
+    let curr_bitset = cache[pos];
+
+    let bitmask = 1 << dir.index();
+
+    (curr_bitset & bitmask) > 0
+}
+
+fn set_bit(cache: &mut Grid<u32>, pos: Point, dir: Direction) {
+    let curr_bitset = cache[pos];
+
+    let bitmask = 1 << dir.index();
+    let bitset = curr_bitset | bitmask;
+
+    cache[pos] = bitset;
+}
+
+fn traverse_2(map: &Grid<u8>, pos: Point) -> bool {
+    let mut cache = Grid::filled(map.width(), map.height(), 0);
+
+    let mut dir = Direction::Up;
+    let mut pos = pos;
+
+    loop {
+        let Some(npos) = map.step(pos, dir) else {
+            return false;
+        };
+
+        if map[npos] == b'#' {
+            if visited_2(&cache, pos, dir) {
+                return true;
+            }
+            set_bit(&mut cache, pos, dir);
+            dir = dir.turn_right();
+        } else {
+            pos = npos;
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut input = grid.clone();
+        traverse_1(&mut input, guard_index(grid));
+        Ok(input
+            .iter()
+            .filter(|(_, c)| **c == b'X')
+            .count()
+            .to_string())
+    }
+
+    fn part2(grid: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let mut input = grid.clone();
+        let start = guard_index(&input);
+
+        traverse_1(&mut input, start);
+
+        let a = input
+            .points()
+            .filter(|&p| input[p] == b'X' && p != start)
+            .collect::<Vec<Point>>()
+            .into_iter()
+            .filter(|&p| {
+                input[p] = b'#';
+                let ans = traverse_2(&input, start);
+                input[p] = b'X';
+                ans
+            })
+            .count();
+        Ok(a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("41", Day6::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("6", Day6::solve_part2(INPUT).unwrap());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (res, nums) = line.split_once(": ")?;
+            let res: u64 = line.parse(res)?;
+            let nums: Vec<u64> = nums
+                .split_ascii_whitespace()
+                .map(|n| line.parse(n))
+                .collect::<Result<_, _>>()?;
+            Ok((res, nums))
+        })
+        .collect()
+}
+
+fn concat(a: u64, b: u64) -> u64 {
+    let mut digits = 0;
+    let mut bb = b;
+    loop {
+        if bb == 0 {
+            break;
+        }
+        bb /= 10;
+        digits += 1;
+    }
+
+    a * 10_u64.pow(digits) + b
+}
+
+fn solveable(ref_value: u64, value: u64, nums: &[u64], third_op: bool) -> bool {
+    if value == ref_value && nums.is_empty() {
+        return true;
+    }
+    if nums.is_empty() || value > ref_value {
+        return false;
+    }
+
+    solveable(ref_value, value + nums[0], &nums[1..], third_op)
+        || solveable(ref_value, value * nums[0], &nums[1..], third_op)
+        || (third_op && solveable(ref_value, concat(value, nums[0]), &nums[1..], third_op))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Parsed = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(input
+            .iter()
+            .filter(|q| solveable(q.0, 0, &q.1[..], false))
+            .map(|q| q.0)
+            .sum::<u64>()
+            .to_string())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(input
+            .iter()
+            .filter(|q| solveable(q.0, 0, &q.1[..], true))
+            .map(|q| q.0)
+            .sum::<u64>()
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("3749", Day7::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("11387", Day7::solve_part2(INPUT).unwrap());
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{Answer, Grid, ParseError, Point, Solution, Unsolvable};
+use itertools::Itertools;
+
+fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input)
+}
+
+fn as_graph(input: &Grid<u8>) -> HashMap<u8, Vec<Point>> {
+    let mut hmap = HashMap::new();
+    for (p, &ch) in input.iter() {
+        if ch != b'.' {
+            hmap.entry(ch).or_insert_with(Vec::new).push(p);
+        }
+    }
+    hmap
+}
+
+fn count_resonance(
+    graph: HashMap<u8, Vec<Point>>,
+    grid: &Grid<u8>,
+    single_resonance: bool,
+) -> usize {
+    let mut resonance_points: HashSet<Point> = HashSet::new();
+    for v in graph.values() {
+        for (&a, &b) in v.iter().tuple_combinations() {
+            let diff = a - b;
+
+            let mut bp = grid.offset(a, diff);
+            while let Some(p) = bp {
+                resonance_points.insert(p);
+                if single_resonance {
+                    break;
+                }
+                bp = grid.offset(p, diff);
+            }
+            let mut fp = grid.offset(b, -diff);
+            while let Some(p) = fp {
+                resonance_points.insert(p);
+                if single_resonance {
+                    break;
+                }
+                fp = grid.offset(p, -diff);
+            }
+        }
+    }
+    if !single_resonance {
+        resonance_points.extend(graph.values().filter(|v| v.len() > 1).flatten());
+    }
+    resonance_points.len()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Parsed = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let graph = as_graph(input);
+        Ok(count_resonance(graph, input, true).to_string())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        let graph = as_graph(input);
+        Ok(count_resonance(graph, input, false).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const INPUT: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+    #[test]
+    fn test_part1() {
+        assert_eq!("14", Day8::solve_part1(INPUT).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!("34", Day8::solve_part2(INPUT).unwrap());
+    }
+}
@@ -0,0 +1,204 @@
+pub mod simulator;
+pub mod strategy;
+
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+
+/// Reads the disk map as single base-36 digits, so `0`-`9` as in the puzzle and `a`-`z`
+/// for sizes up to 35, or as comma-separated sizes of any length.
+fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    let line = lines(input)
+        .next()
+        .ok_or_else(|| ParseError::new("empty disk map"))?;
+    if line.text.contains(',') {
+        return line
+            .text
+            .split(',')
+            .map(|size| line.parse::<u32>(size).map(i64::from))
+            .collect();
+    }
+    line.text
+        .trim()
+        .chars()
+        .map(|ch| {
+            ch.to_digit(36)
+                .map(i64::from)
+                .ok_or_else(|| line.error(format!("unexpected character `{ch}`")))
+        })
+        .collect()
+}
+
+fn asum(a_1: i64, a_n: i64, n: i64) -> i64 {
+    n * (a_1 + a_n) / 2
+}
+
+/// The largest free space in each range of gaps, leftmost gap first, so that the
+/// leftmost gap a file fits in can be found in O(log N) whatever the sizes.
+struct GapTree {
+    leaves: usize,
+    max: Vec<i64>,
+}
+
+impl GapTree {
+    fn new(gaps: &[i64]) -> Self {
+        let leaves = gaps.len().next_power_of_two();
+        let mut max = vec![-1; 2 * leaves];
+        max[leaves..leaves + gaps.len()].copy_from_slice(gaps);
+        for i in (1..leaves).rev() {
+            max[i] = max[2 * i].max(max[2 * i + 1]);
+        }
+        GapTree { leaves, max }
+    }
+
+    /// The index of the leftmost gap with at least `size` free blocks.
+    fn leftmost(&self, size: i64) -> Option<usize> {
+        if self.max[1] < size {
+            return None;
+        }
+        let mut i = 1;
+        while i < self.leaves {
+            i = if self.max[2 * i] >= size {
+                2 * i
+            } else {
+                2 * i + 1
+            };
+        }
+        Some(i - self.leaves)
+    }
+
+    fn set(&mut self, gap: usize, size: i64) {
+        let mut i = gap + self.leaves;
+        self.max[i] = size;
+        while i > 1 {
+            i /= 2;
+            self.max[i] = self.max[2 * i].max(self.max[2 * i + 1]);
+        }
+    }
+}
+
+/// The checksum after moving single blocks from the end into the leftmost free
+/// space, without building the disk. O(N)
+pub fn fragmented_checksum(disk_map: &[i64]) -> i64 {
+    let mut input = disk_map.to_vec();
+
+    let mut ans = 0;
+    let mut free_idx = 0;
+    let mut i = 0;
+    // The last file, as the map may end with free space.
+    let mut j = (input.len() - 1) / 2 * 2;
+
+    while i <= j {
+        if i % 2 == 0 {
+            let blocks = input[i];
+            let val = asum(free_idx, free_idx + blocks - 1, blocks) * (i / 2) as i64;
+
+            ans += val;
+            free_idx += blocks;
+            i += 1;
+        } else {
+            let blocks = std::cmp::min(input[j], input[i]);
+            let val = asum(free_idx, free_idx + blocks - 1, blocks) * (j / 2) as i64;
+
+            ans += val;
+            free_idx += blocks;
+            input[i] -= blocks;
+            input[j] -= blocks;
+
+            if input[i] <= 0 {
+                i += 1;
+            }
+            if input[j] <= 0 {
+                j -= 2;
+            }
+        }
+    }
+    ans
+}
+
+/// The checksum after moving whole files, highest id first, into the leftmost gap
+/// before them that fits, without building the disk. O(N log N)
+pub fn whole_file_checksum(disk_map: &[i64]) -> i64 {
+    let starts: Vec<i64> = disk_map
+        .iter()
+        .scan(0, |pos, &size| {
+            let start = *pos;
+            *pos += size;
+            Some(start)
+        })
+        .collect();
+    // Gap `i` is the free space after file `i`, which shrinks from the left as files move in.
+    let mut gap_starts: Vec<i64> = starts.iter().skip(1).step_by(2).copied().collect();
+    let mut gaps: Vec<i64> = disk_map.iter().skip(1).step_by(2).copied().collect();
+    let mut tree = GapTree::new(&gaps);
+
+    let mut ans = 0;
+    for (i, &size) in disk_map.iter().enumerate().step_by(2).rev() {
+        if size == 0 {
+            continue;
+        }
+        let id = (i / 2) as i64;
+        let mut pos = starts[i];
+        if let Some(gap) = tree.leftmost(size).filter(|&gap| gap < i / 2) {
+            pos = gap_starts[gap];
+            gap_starts[gap] += size;
+            gaps[gap] -= size;
+            tree.set(gap, gaps[gap]);
+        }
+        ans += asum(pos, pos + size - 1, size) * id;
+    }
+    ans
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Parsed = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(disk_map: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(fragmented_checksum(disk_map).to_string())
+    }
+
+    fn part2(disk_map: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(whole_file_checksum(disk_map).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "2333133121414131402";
+        assert_eq!("1928", Day9::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    fn test_part2() {
+        //01..
+        let input = "2333133121414131402";
+
+        assert_eq!("2858", Day9::solve_part2(input).unwrap());
+    }
+
+    #[test]
+    fn test_extended_format() {
+        let digits = Day9::parse("2333133121414131402").unwrap();
+        let sizes = "2, 3, 3, 3, 1, 3, 3, 1, 2, 1, 4, 1, 4, 1, 3, 1, 4, 0, 2";
+        assert_eq!(digits, Day9::parse(sizes).unwrap());
+        assert_eq!(vec![10, 35, 0], Day9::parse("aZ0").unwrap());
+
+        // Sizes above 9 must still come out the same as moving the blocks one by one.
+        for input in ["c3a1f0b2z4", "1,20,3,0,17,11,2,40,9", "2z1y3x4w5v"] {
+            let disk_map = Day9::parse(input).unwrap();
+            for part in [1, 2] {
+                assert!(simulator::simulate(&disk_map, part).is_ok(), "{input}");
+            }
+        }
+    }
+}
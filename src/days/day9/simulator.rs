@@ -0,0 +1,109 @@
+use std::{
+    fmt::{self, Display},
+    iter,
+};
+
+use super::{fragmented_checksum, strategy::Strategy, whole_file_checksum};
+use crate::Unsolvable;
+
+/// A disk laid out block by block, each block holding a file id or nothing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disk {
+    pub blocks: Vec<Option<usize>>,
+}
+
+impl Disk {
+    /// Expands a disk map of alternating file and free space lengths.
+    pub fn new(disk_map: &[i64]) -> Self {
+        let mut blocks = Vec::new();
+        for (i, &size) in disk_map.iter().enumerate() {
+            let id = (i % 2 == 0).then_some(i / 2);
+            blocks.extend(iter::repeat_n(id, size as usize));
+        }
+        Disk { blocks }
+    }
+
+    /// The id, first block and length of each file, in the order they are on disk.
+    pub fn files(&self) -> Vec<(usize, usize, usize)> {
+        let mut files = Vec::new();
+        let mut start = 0;
+        for run in self.blocks.chunk_by(|a, b| a == b) {
+            if let Some(id) = run[0] {
+                files.push((id, start, run.len()));
+            }
+            start += run.len();
+        }
+        files
+    }
+
+    pub fn checksum(&self) -> i64 {
+        self.blocks
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, id)| Some((pos * (*id)?) as i64))
+            .sum()
+    }
+}
+
+/// Draws the disk like the puzzle does, e.g. `0099811188827773336446555566......`.
+/// Ids above 9 are written in parentheses so that they can't be confused.
+impl Display for Disk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for block in &self.blocks {
+            match block {
+                None => write!(f, ".")?,
+                Some(id @ 0..=9) => write!(f, "{id}")?,
+                Some(id) => write!(f, "({id})")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compacts the disk block by block as `part` does, checking that the resulting checksum
+/// is the one the solution computes without building the disk.
+pub fn simulate(disk_map: &[i64], part: u8) -> Result<Disk, Unsolvable> {
+    let mut disk = Disk::new(disk_map);
+    disk.compact(Strategy::for_part(part));
+    let expected = match part {
+        1 => fragmented_checksum(disk_map),
+        _ => whole_file_checksum(disk_map),
+    };
+    let simulated = disk.checksum();
+    if simulated != expected {
+        return Err(Unsolvable::new(format!(
+            "the simulated disk has checksum {simulated}, but the solution computes {expected}"
+        )));
+    }
+    Ok(disk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day9::Day9, Solution};
+
+    #[test]
+    fn test_simulate() {
+        let disk_map = Day9::parse("2333133121414131402").unwrap();
+        assert_eq!(
+            "00...111...2...333.44.5555.6666.777.888899",
+            Disk::new(&disk_map).to_string()
+        );
+        let disk = simulate(&disk_map, 1).unwrap();
+        assert_eq!(
+            "0099811188827773336446555566..............",
+            disk.to_string()
+        );
+        assert_eq!(1928, disk.checksum());
+        let disk = simulate(&disk_map, 2).unwrap();
+        assert_eq!(
+            "00992111777.44.333....5555.6666.....8888..",
+            disk.to_string()
+        );
+        assert_eq!(2858, disk.checksum());
+
+        let disk = Disk::new(&Day9::parse("10101010101010101010101").unwrap());
+        assert_eq!("0123456789(10)(11)", disk.to_string());
+    }
+}
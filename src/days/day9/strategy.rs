@@ -0,0 +1,323 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use super::simulator::Disk;
+
+/// Which blocks move where when compacting the disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Policy {
+    /// Single blocks from the end into the leftmost free block, splitting files (part 1).
+    Blocks,
+    /// Whole files into the leftmost gap that fits (part 2).
+    #[default]
+    FirstFit,
+    /// Whole files into the smallest gap that fits.
+    BestFit,
+    /// Whole files into the largest gap.
+    WorstFit,
+    /// Whole files into the first gap that fits after the previous one filled,
+    /// wrapping around to the start of the disk.
+    NextFit,
+}
+
+impl Policy {
+    pub const ALL: [Policy; 5] = [
+        Policy::Blocks,
+        Policy::FirstFit,
+        Policy::BestFit,
+        Policy::WorstFit,
+        Policy::NextFit,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Blocks => "blocks",
+            Policy::FirstFit => "first-fit",
+            Policy::BestFit => "best-fit",
+            Policy::WorstFit => "worst-fit",
+            Policy::NextFit => "next-fit",
+        }
+    }
+}
+
+impl Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A policy's name, with or without the `-fit`.
+impl FromStr for Policy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let s = s.trim();
+        Policy::ALL
+            .into_iter()
+            .find(|policy| policy.name() == s || policy.name().strip_suffix("-fit") == Some(s))
+            .ok_or_else(|| format!("Invalid strategy: {s}"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Strategy {
+    pub policy: Policy,
+    /// Keep making passes over the files until one moves nothing, rather than moving
+    /// each file at most once.
+    pub until_fixpoint: bool,
+}
+
+impl Strategy {
+    /// The strategy the puzzle describes for `part`.
+    pub fn for_part(part: u8) -> Self {
+        Strategy {
+            policy: if part == 1 {
+                Policy::Blocks
+            } else {
+                Policy::FirstFit
+            },
+            until_fixpoint: false,
+        }
+    }
+}
+
+impl Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.policy)?;
+        if self.until_fixpoint {
+            write!(f, " until fixpoint")?;
+        }
+        Ok(())
+    }
+}
+
+/// How much free space is still stuck between files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fragmentation {
+    /// Runs of free blocks before the last file block.
+    pub gaps: usize,
+    /// Free blocks in those gaps.
+    pub free_blocks: usize,
+    /// Files whose blocks are not all in one piece.
+    pub split_files: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Report {
+    pub strategy: Strategy,
+    pub checksum: i64,
+    /// Blocks moved for [`Policy::Blocks`], whole files otherwise.
+    pub moves: usize,
+    pub passes: usize,
+    pub fragmentation: Fragmentation,
+}
+
+/// The free runs of a disk by their first block, merged as files move out.
+struct Gaps(BTreeMap<usize, usize>);
+
+impl Gaps {
+    fn new(disk: &Disk) -> Self {
+        let mut gaps = BTreeMap::new();
+        let mut start = 0;
+        for run in disk.blocks.chunk_by(|a, b| a == b) {
+            if run[0].is_none() {
+                gaps.insert(start, run.len());
+            }
+            start += run.len();
+        }
+        Gaps(gaps)
+    }
+
+    /// Where `policy` puts a file of `size` blocks that starts at `before`. `cursor` is
+    /// where next-fit starts looking.
+    fn choose(&self, policy: Policy, size: usize, before: usize, cursor: usize) -> Option<usize> {
+        let mut fitting = (self.0.range(..before))
+            .filter(|(_, &len)| len >= size)
+            .map(|(&start, &len)| (start, len));
+        let (start, _) = match policy {
+            Policy::Blocks | Policy::FirstFit => fitting.next(),
+            Policy::BestFit => fitting.min_by_key(|&(start, len)| (len, start)),
+            Policy::WorstFit => fitting.max_by_key(|&(start, len)| (len, before - start)),
+            Policy::NextFit => {
+                let fitting: Vec<_> = fitting.collect();
+                (fitting.iter().find(|&&(start, _)| start >= cursor))
+                    .or(fitting.first())
+                    .copied()
+            }
+        }?;
+        Some(start)
+    }
+
+    /// Fills the first `size` blocks of the gap at `start`.
+    fn take(&mut self, start: usize, size: usize) {
+        let len = self.0.remove(&start).unwrap();
+        if len > size {
+            self.0.insert(start + size, len - size);
+        }
+    }
+
+    /// Frees `len` blocks at `start`, merging them with the gaps on either side.
+    fn release(&mut self, mut start: usize, mut len: usize) {
+        if let Some((&before, &before_len)) = self.0.range(..start).next_back() {
+            if before + before_len == start {
+                self.0.remove(&before);
+                start = before;
+                len += before_len;
+            }
+        }
+        if let Some(after_len) = self.0.remove(&(start + len)) {
+            len += after_len;
+        }
+        self.0.insert(start, len);
+    }
+}
+
+impl Disk {
+    /// Compacts the disk with `strategy`, returning what it took and what is left.
+    pub fn compact(&mut self, strategy: Strategy) -> Report {
+        let (moves, passes) = match strategy.policy {
+            Policy::Blocks => (self.move_blocks(), 1),
+            policy => self.move_files(policy, strategy.until_fixpoint),
+        };
+        Report {
+            strategy,
+            checksum: self.checksum(),
+            moves,
+            passes,
+            fragmentation: self.fragmentation(),
+        }
+    }
+
+    /// Swaps the last file block into the first free one until there are no gaps left,
+    /// returning the number of blocks moved.
+    fn move_blocks(&mut self) -> usize {
+        let (mut free, mut end, mut moves) = (0, self.blocks.len(), 0);
+        loop {
+            while free < end && self.blocks[free].is_some() {
+                free += 1;
+            }
+            while end > free && self.blocks[end - 1].is_none() {
+                end -= 1;
+            }
+            if free >= end {
+                return moves;
+            }
+            self.blocks.swap(free, end - 1);
+            moves += 1;
+        }
+    }
+
+    /// Moves files highest id first to where `policy` puts them, once each or until
+    /// a pass moves nothing. Returns the number of files moved and passes made.
+    fn move_files(&mut self, policy: Policy, until_fixpoint: bool) -> (usize, usize) {
+        let mut files = self.files();
+        files.sort_by_key(|&(id, start, _)| (id, start));
+        let mut gaps = Gaps::new(self);
+        let (mut cursor, mut moves, mut passes) = (0, 0, 0);
+        loop {
+            let mut moved = 0;
+            for (id, start, len) in files.iter_mut().rev() {
+                let Some(to) = gaps.choose(policy, *len, *start, cursor) else {
+                    continue;
+                };
+                self.blocks[to..to + *len].fill(Some(*id));
+                self.blocks[*start..*start + *len].fill(None);
+                gaps.take(to, *len);
+                gaps.release(*start, *len);
+                *start = to;
+                cursor = to + *len;
+                moved += 1;
+            }
+            moves += moved;
+            passes += 1;
+            if moved == 0 || !until_fixpoint {
+                return (moves, passes);
+            }
+        }
+    }
+
+    pub fn fragmentation(&self) -> Fragmentation {
+        let end = self
+            .blocks
+            .iter()
+            .rposition(Option::is_some)
+            .map_or(0, |i| i + 1);
+        let gaps: Vec<usize> = (self.blocks[..end].chunk_by(|a, b| a == b))
+            .filter(|run| run[0].is_none())
+            .map(<[_]>::len)
+            .collect();
+        let mut ids: Vec<usize> = self.files().into_iter().map(|(id, _, _)| id).collect();
+        ids.sort_unstable();
+        let pieces = ids.len();
+        ids.dedup();
+        Fragmentation {
+            gaps: gaps.len(),
+            free_blocks: gaps.iter().sum(),
+            split_files: pieces - ids.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days::day9::Day9, Solution};
+
+    #[test]
+    fn test_strategies() {
+        let disk_map = Day9::parse("2333133121414131402").unwrap();
+        let compact = |policy, until_fixpoint| {
+            let mut disk = Disk::new(&disk_map);
+            let report = disk.compact(Strategy {
+                policy,
+                until_fixpoint,
+            });
+            (disk.to_string(), report)
+        };
+
+        let (layout, report) = compact(Policy::FirstFit, false);
+        assert_eq!("00992111777.44.333....5555.6666.....8888..", layout);
+        assert_eq!((2858, 4, 1), (report.checksum, report.moves, report.passes));
+        assert_eq!(
+            Fragmentation {
+                gaps: 5,
+                free_blocks: 12,
+                split_files: 0
+            },
+            report.fragmentation
+        );
+
+        let (_, report) = compact(Policy::Blocks, false);
+        assert_eq!(
+            (1928, 12, 0),
+            (report.checksum, report.moves, report.fragmentation.gaps)
+        );
+        assert_eq!(3, report.fragmentation.split_files);
+
+        let (layout, report) = compact(Policy::FirstFit, true);
+        assert_eq!("00992111777.44.33388885555.6666...........", layout);
+        assert_eq!(
+            (5, 3, 3),
+            (report.moves, report.passes, report.fragmentation.gaps)
+        );
+
+        let layout = |disk_map, policy| {
+            let mut disk = Disk::new(&Day9::parse(disk_map).unwrap());
+            disk.compact(Strategy {
+                policy,
+                until_fixpoint: false,
+            });
+            disk.to_string()
+        };
+        // 33 fits the first gap, but only best-fit takes the one that fits it exactly.
+        assert_eq!("03321.....", layout("1312102", Policy::FirstFit));
+        assert_eq!("021..33...", layout("1312102", Policy::BestFit));
+        assert_eq!("0331.2....", layout("1312102", Policy::WorstFit));
+        // 44 goes into the second gap, after which next-fit moves 3 past the first.
+        assert_eq!("031442....", layout("111211102", Policy::FirstFit));
+        assert_eq!("02144.3...", layout("111211102", Policy::NextFit));
+    }
+}
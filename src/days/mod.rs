@@ -0,0 +1,94 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::{
+    bench::{self, Config, Timing},
+    Answer, AocError, Solution,
+};
+
+/// Parses an input and runs the given parts on it.
+pub type SolveFn = fn(&str, &[u8]) -> Result<Vec<Answer>, AocError>;
+/// Times parsing and the given parts on an input.
+pub type BenchFn = fn(&str, &[u8], &Config) -> Result<Vec<Timing>, AocError>;
+
+/// A registered puzzle. New days are added by copying `template.rs` to `dayN.rs`
+/// and appending an entry to `PUZZLES`.
+pub struct Puzzle {
+    pub day: u8,
+    pub solve: SolveFn,
+    pub bench: BenchFn,
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Answer>, AocError> {
+    let parsed = S::read(input)?;
+    parts.iter().map(|&part| S::run(part, &parsed)).collect()
+}
+
+impl Puzzle {
+    const fn new<S: Solution>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day{}", self.day)
+    }
+}
+
+pub const PUZZLES: [Puzzle; 25] = [
+    Puzzle::new::<day1::Day1>(),
+    Puzzle::new::<day2::Day2>(),
+    Puzzle::new::<day3::Day3>(),
+    Puzzle::new::<day4::Day4>(),
+    Puzzle::new::<day5::Day5>(),
+    Puzzle::new::<day6::Day6>(),
+    Puzzle::new::<day7::Day7>(),
+    Puzzle::new::<day8::Day8>(),
+    Puzzle::new::<day9::Day9>(),
+    Puzzle::new::<day10::Day10>(),
+    Puzzle::new::<day11::Day11>(),
+    Puzzle::new::<day12::Day12>(),
+    Puzzle::new::<day13::Day13>(),
+    Puzzle::new::<day14::Day14>(),
+    Puzzle::new::<day15::Day15>(),
+    Puzzle::new::<day16::Day16>(),
+    Puzzle::new::<day17::Day17>(),
+    Puzzle::new::<day18::Day18>(),
+    Puzzle::new::<day19::Day19>(),
+    Puzzle::new::<day20::Day20>(),
+    Puzzle::new::<day21::Day21>(),
+    Puzzle::new::<day22::Day22>(),
+    Puzzle::new::<day23::Day23>(),
+    Puzzle::new::<day24::Day24>(),
+    Puzzle::new::<day25::Day25>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
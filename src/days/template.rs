@@ -0,0 +1,47 @@
+use crate::{parse::lines, Answer, ParseError, Solution, Unsolvable};
+
+fn parse_input(input: &str) -> Result<(), ParseError> {
+    for line in lines(input) {
+        todo!()
+    }
+    Ok(())
+}
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const DAY: u8 = 0;
+
+    type Parsed = ();
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(String::new())
+    }
+
+    fn part2(input: &Self::Parsed) -> Result<Answer, Unsolvable> {
+        Ok(String::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "";
+        assert_eq!("", DayX::solve_part1(input).unwrap());
+    }
+
+    #[test]
+    #[ignore]
+    fn test_part2() {
+        let input = "";
+
+        assert_eq!("", DayX::solve_part2(input).unwrap());
+    }
+}
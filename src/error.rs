@@ -0,0 +1,138 @@
+use std::{
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+/// Malformed puzzle input. `line` is 1-based and `None` when the problem is not tied to
+/// a single line (e.g. a missing section or marker).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            text: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn at(line: usize, text: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line: Some(line),
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {line}: {}\n    | {}", self.message, self.text),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A well-formed input for which a part has no answer, e.g. a maze without a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Unsolvable(pub String);
+
+impl Unsolvable {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Unsolvable(reason.into())
+    }
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Unsolvable {}
+
+#[derive(Debug)]
+pub enum AocError {
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    MissingInput {
+        day: u8,
+        tried: Vec<PathBuf>,
+        available: Vec<String>,
+    },
+    Fetch {
+        day: u8,
+        url: String,
+        message: String,
+    },
+    Parse {
+        day: u8,
+        source: ParseError,
+    },
+    /// A file other than a puzzle input, such as the stored answers, is malformed.
+    InvalidFile {
+        path: PathBuf,
+        source: ParseError,
+    },
+    Unsolvable {
+        day: u8,
+        part: u8,
+        source: Unsolvable,
+    },
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => {
+                write!(f, "could not read {}: {source}", path.display())
+            }
+            AocError::MissingInput {
+                day,
+                tried,
+                available,
+            } => {
+                write!(f, "no input found for day{day}, tried:")?;
+                for path in tried {
+                    write!(f, "\n    {}", path.display())?;
+                }
+                if !available.is_empty() {
+                    write!(f, "\navailable named inputs: {}", available.join(", "))?;
+                }
+                Ok(())
+            }
+            AocError::Fetch { day, url, message } => {
+                write!(f, "could not download day{day} input from {url}: {message}")
+            }
+            AocError::Parse { day, source } => write!(f, "day{day}: invalid input: {source}"),
+            AocError::InvalidFile { path, source } => {
+                write!(f, "invalid {}: {source}", path.display())
+            }
+            AocError::Unsolvable { day, part, source } => {
+                write!(f, "day{day} part {part}: no solution: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::MissingInput { .. } | AocError::Fetch { .. } => None,
+            AocError::Parse { source, .. } | AocError::InvalidFile { source, .. } => Some(source),
+            AocError::Unsolvable { source, .. } => Some(source),
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    net::TcpStream,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::AocError;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = "github.com/FusionX9000/Advent-Of-Code-2024";
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Minimal HTTP GET used to download inputs. Implementations only need to support
+/// the URLs they are handed by [`Fetcher`].
+pub trait HttpClient {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response>;
+}
+
+/// Uses the `curl` binary, which gives us TLS without pulling in an HTTP stack.
+/// Headers are passed on stdin so the session token doesn't show up in `ps`.
+pub struct Curl;
+
+impl HttpClient for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--header", "@-"])
+            .args(["--write-out", "\n%{http_code}", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut stdin = child.stdin.take().unwrap();
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+        drop(stdin);
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+        let status = status
+            .parse()
+            .map_err(|_| io::Error::other(format!("unexpected status `{status}` from curl")))?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// Plain-text HTTP/1.1 over a `TcpStream`. Only meant for local servers (e.g. a stub
+/// in tests), it supports neither TLS nor chunked responses.
+pub struct PlainHttp;
+
+impl HttpClient for PlainHttp {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidInput, format!("bad url `{url}`"));
+        let rest = url.strip_prefix("http://").ok_or_else(invalid)?;
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let path = if path.is_empty() { "/" } else { path };
+
+        let mut stream = TcpStream::connect(host)?;
+        write!(
+            stream,
+            "GET {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n"
+        )?;
+        for (name, value) in headers {
+            write!(stream, "{name}: {value}\r\n")?;
+        }
+        write!(stream, "\r\n")?;
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response)?;
+        let (head, body) = response.split_once("\r\n\r\n").ok_or_else(invalid)?;
+        let status = head
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(invalid)?;
+        Ok(Response {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+pub fn client_for(url: &str) -> Box<dyn HttpClient> {
+    if url.starts_with("http://") {
+        Box::new(PlainHttp)
+    } else {
+        Box::new(Curl)
+    }
+}
+
+/// What we know about a downloaded input, stored next to it as `dayN.meta`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    pub url: String,
+    pub fetched_at: u64,
+    pub bytes: usize,
+}
+
+impl Metadata {
+    fn to_text(&self) -> String {
+        format!(
+            "url: {}\nfetched_at: {}\nbytes: {}\n",
+            self.url, self.fetched_at, self.bytes
+        )
+    }
+
+    fn from_text(text: &str) -> Option<Self> {
+        let field = |name: &str| {
+            text.lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+        };
+        Some(Metadata {
+            url: field("url")?.to_string(),
+            fetched_at: field("fetched_at")?.parse().ok()?,
+            bytes: field("bytes")?.parse().ok()?,
+        })
+    }
+}
+
+/// Downloaded inputs live in the regular input directory so the rest of the input
+/// handling doesn't need to know where they came from.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day}.txt"))
+    }
+
+    pub fn metadata_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day}.meta"))
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.input_path(day).is_file()
+    }
+
+    pub fn metadata(&self, day: u8) -> Option<Metadata> {
+        Metadata::from_text(&fs::read_to_string(self.metadata_path(day)).ok()?)
+    }
+
+    fn store(&self, day: u8, input: &str, metadata: &Metadata) -> Result<(), AocError> {
+        let write = |path: PathBuf, contents: &str| {
+            fs::write(&path, contents).map_err(|source| AocError::Io { path, source })
+        };
+        fs::create_dir_all(&self.dir).map_err(|source| AocError::Io {
+            path: self.dir.clone(),
+            source,
+        })?;
+        write(self.input_path(day), input)?;
+        write(self.metadata_path(day), &metadata.to_text())
+    }
+}
+
+pub struct Fetcher {
+    client: Box<dyn HttpClient>,
+    base_url: String,
+    session: String,
+}
+
+impl Fetcher {
+    pub fn new(client: Box<dyn HttpClient>, base_url: &str, session: &str) -> Self {
+        Fetcher {
+            client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Configured from `AOC_SESSION` (required) and `AOC_BASE_URL`.
+    pub fn from_env() -> Option<Self> {
+        let session = env::var(SESSION_VAR).ok()?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or(DEFAULT_BASE_URL.to_string());
+        Some(Fetcher::new(client_for(&base_url), &base_url, &session))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!("{}/{YEAR}/day/{day}/input", self.base_url)
+    }
+
+    /// Returns the cached input, downloading it first if it has never been fetched.
+    pub fn fetch(&self, day: u8, cache_dir: &Path) -> Result<String, AocError> {
+        let cache = Cache::new(cache_dir);
+        let path = cache.input_path(day);
+        if cache.contains(day) {
+            return fs::read_to_string(&path).map_err(|source| AocError::Io { path, source });
+        }
+
+        let url = self.url(day);
+        let cookie = format!("session={}", self.session);
+        let fetch_error = |message: String| AocError::Fetch {
+            day,
+            url: url.clone(),
+            message,
+        };
+        let response = self
+            .client
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])
+            .map_err(|e| fetch_error(e.to_string()))?;
+        match response.status {
+            200 => {}
+            400 | 500 => return Err(fetch_error("session token was rejected".to_string())),
+            404 => return Err(fetch_error("puzzle is not unlocked yet".to_string())),
+            status => return Err(fetch_error(format!("unexpected HTTP status {status}"))),
+        }
+
+        let metadata = Metadata {
+            url: url.clone(),
+            fetched_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            bytes: response.body.len(),
+        };
+        cache.store(day, &response.body, &metadata)?;
+        Ok(response.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::BufRead,
+        io::BufReader,
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves `body` for every request and counts the requests that carried a session.
+    fn stub_server(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map_while(Result::ok)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                if request.iter().any(|line| line == "Cookie: session=token") {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, hits)
+    }
+
+    #[test]
+    fn test_fetch_is_cached() {
+        let (url, hits) = stub_server("1   2\n");
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let fetcher = Fetcher::new(Box::new(PlainHttp), &url, "token");
+
+        assert_eq!("1   2\n", fetcher.fetch(1, &dir).unwrap());
+        assert_eq!("1   2\n", fetcher.fetch(1, &dir).unwrap());
+        assert_eq!(1, hits.load(Ordering::SeqCst));
+
+        let metadata = Cache::new(&dir).metadata(1).unwrap();
+        assert_eq!(format!("{url}/2024/day/1/input"), metadata.url);
+        assert_eq!(6, metadata.bytes);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,183 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A cell position, `row` counting down from the top and `col` right from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Point {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Point { row, col }
+    }
+
+    /// `self + vector`, or `None` if that would leave the non-negative quadrant.
+    /// [`Grid::offset`](crate::Grid::offset) also checks the grid's bounds.
+    pub fn checked_add(self, vector: Vector) -> Option<Point> {
+        Some(Point::new(
+            self.row.checked_add_signed(vector.dr)?,
+            self.col.checked_add_signed(vector.dc)?,
+        ))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+/// The vector from `other` to `self`.
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(
+            self.row as isize - other.row as isize,
+            self.col as isize - other.col as isize,
+        )
+    }
+}
+
+/// A displacement between points, in rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vector {
+    pub dr: isize,
+    pub dc: isize,
+}
+
+impl Vector {
+    pub const fn new(dr: isize, dc: isize) -> Self {
+        Vector { dr, dc }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.dr.unsigned_abs() + self.dc.unsigned_abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dr + other.dr, self.dc + other.dc)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dr, -self.dc)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: isize) -> Vector {
+        Vector::new(self.dr * n, self.dc * n)
+    }
+}
+
+/// One of the four grid directions. `Up` is towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise starting up, the order [`Direction::index`] follows.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Position in [`Direction::ALL`], for per-direction arrays and bitsets.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(-1, 0),
+            Direction::Right => Vector::new(0, 1),
+            Direction::Down => Vector::new(1, 0),
+            Direction::Left => Vector::new(0, -1),
+        }
+    }
+
+    /// Parses one of the arrows `^>v<`.
+    pub fn from_char(ch: char) -> Option<Direction> {
+        match ch {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.reverse(), dir.turn_right().turn_right());
+            assert_eq!(Some(dir), Direction::from_char(dir.to_char()));
+            assert_eq!(-dir.vector(), dir.reverse().vector());
+        }
+        assert_eq!(Direction::Left, Direction::Up.turn_left());
+    }
+
+    #[test]
+    fn test_point_arithmetic() {
+        let (a, b) = (Point::new(1, 5), Point::new(4, 1));
+        assert_eq!(Vector::new(3, -4), b - a);
+        assert_eq!(Some(b), a.checked_add(b - a));
+        assert_eq!(None, a.checked_add(Direction::Up.vector() * 2));
+        assert_eq!(7, a.manhattan(b));
+        assert_eq!(7, (a - b).manhattan());
+    }
+}
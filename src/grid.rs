@@ -0,0 +1,227 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Direction, Point, Vector},
+    parse::{grid_lines, Line},
+    ParseError,
+};
+
+/// Offsets of all 8 neighbours, clockwise starting up.
+const ADJACENT: [Vector; 8] = [
+    Vector::new(-1, 0),
+    Vector::new(-1, 1),
+    Vector::new(0, 1),
+    Vector::new(1, 1),
+    Vector::new(1, 0),
+    Vector::new(1, -1),
+    Vector::new(0, -1),
+    Vector::new(-1, -1),
+];
+
+/// A rectangular grid stored row-major in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from row-major cells. Panics if they don't fill whole rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fit rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parses a rectangular block of text, mapping each byte with `cell`. A `None`
+    /// from `cell` is reported as an unexpected character on that line.
+    pub fn parse_with(input: &str, cell: impl FnMut(u8) -> Option<T>) -> Result<Self, ParseError> {
+        Grid::from_lines(&grid_lines(input)?, cell)
+    }
+
+    /// Like [`Grid::parse_with`], for grids that are one section of a larger input.
+    pub fn from_lines(
+        rows: &[Line],
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let width = rows
+            .first()
+            .ok_or_else(|| ParseError::new("empty grid"))?
+            .text
+            .trim()
+            .len();
+        let mut cells = Vec::with_capacity(width * rows.len());
+        for row in rows {
+            let text = row.text.trim();
+            if text.len() != width {
+                return Err(row.error(format!("expected {width} columns, found {}", text.len())));
+            }
+            for byte in text.bytes() {
+                let value = cell(byte)
+                    .ok_or_else(|| row.error(format!("unexpected character `{}`", byte as char)))?;
+                cells.push(value);
+            }
+        }
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.row < self.height && point.col < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.row * self.width + point.col])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            Some(&mut self.cells[point.row * self.width + point.col])
+        } else {
+            None
+        }
+    }
+
+    /// `point + vector`, if it lies inside the grid.
+    pub fn offset(&self, point: Point, vector: Vector) -> Option<Point> {
+        point
+            .checked_add(vector)
+            .filter(|&next| self.contains(next))
+    }
+
+    /// The neighbour of `point` in direction `dir`, if it lies inside the grid.
+    pub fn step(&self, point: Point, dir: Direction) -> Option<Point> {
+        self.offset(point, dir.vector())
+    }
+
+    /// Up, right, down and left of `point`, skipping those outside the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// All 8 cells around `point`, diagonals included, skipping those outside the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |offset| self.offset(point, offset))
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point::new(i / width, i % width))
+    }
+
+    /// All cells with their points, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The first point holding `value`, e.g. a start marker.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Grid::from_cells(width, vec![value; width * height])
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of raw bytes, accepting any character.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        &self.cells[point.row * self.width + point.col]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(point), "{point:?} is outside the grid");
+        &mut self.cells[point.row * self.width + point.col]
+    }
+}
+
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let line: String = row.iter().map(|&cell| cell.into()).collect();
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse("#.S\n..#\n").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(Some(Point::new(0, 2)), grid.find(&b'S'));
+        assert_eq!(None, grid.get(Point::new(2, 0)));
+        assert_eq!("#.S\n..#\n", grid.to_string());
+
+        let err = Grid::parse_with("..\n.x", |b| (b == b'.').then_some(b)).unwrap_err();
+        assert_eq!(Some(2), err.line);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::filled(3, 3, 0);
+        let corner: Vec<Point> = grid.neighbours4(Point::new(0, 0)).collect();
+        assert_eq!(vec![Point::new(0, 1), Point::new(1, 0)], corner);
+        assert_eq!(8, grid.neighbours8(Point::new(1, 1)).count());
+        assert_eq!(3, grid.neighbours8(Point::new(2, 2)).count());
+    }
+}
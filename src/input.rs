@@ -0,0 +1,164 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{fetch::Fetcher, AocError};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where puzzle inputs are read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    /// Directories searched in order, the first one containing the file wins.
+    Dirs(Vec<PathBuf>),
+}
+
+/// Resolves and reads puzzle inputs. Inputs are named `dayN.txt`, with extra named
+/// inputs (e.g. examples) stored alongside as `dayN.NAME.txt`. With a [`Fetcher`],
+/// missing main inputs are downloaded into the first input directory.
+pub struct Inputs {
+    source: InputSource,
+    fetcher: Option<Fetcher>,
+}
+
+impl Inputs {
+    /// `dir` comes from the command line and takes precedence over `AOC_INPUT_DIR`.
+    /// `-` reads from stdin. Without either, `inputs/` is looked up in the current
+    /// directory and then in the crate root.
+    pub fn new(dir: Option<&str>) -> Self {
+        let dir = dir
+            .map(String::from)
+            .or_else(|| env::var(INPUT_DIR_VAR).ok());
+        let source = match dir.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(dir) => InputSource::Dirs(vec![PathBuf::from(dir)]),
+            None => InputSource::Dirs(vec![
+                PathBuf::from("inputs"),
+                Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+            ]),
+        };
+        Inputs {
+            source,
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(self, fetcher: Fetcher) -> Self {
+        Inputs {
+            fetcher: Some(fetcher),
+            ..self
+        }
+    }
+
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    pub fn file_name(day: u8, name: Option<&str>) -> String {
+        match name {
+            Some(name) => format!("day{day}.{name}.txt"),
+            None => format!("day{day}.txt"),
+        }
+    }
+
+    /// Candidate paths for an input, in lookup order.
+    pub fn candidates(&self, day: u8, name: Option<&str>) -> Vec<PathBuf> {
+        match &self.source {
+            InputSource::Stdin => Vec::new(),
+            InputSource::Dirs(dirs) => dirs
+                .iter()
+                .map(|dir| dir.join(Self::file_name(day, name)))
+                .collect(),
+        }
+    }
+
+    /// The directory inputs and answers are written to: the first searched one that
+    /// exists, or the first one if none do.
+    pub fn primary_dir(&self) -> Option<&Path> {
+        match &self.source {
+            InputSource::Stdin => None,
+            InputSource::Dirs(dirs) => dirs
+                .iter()
+                .find(|dir| dir.is_dir())
+                .or(dirs.first())
+                .map(PathBuf::as_path),
+        }
+    }
+
+    pub fn find(&self, day: u8, name: Option<&str>) -> Option<PathBuf> {
+        self.candidates(day, name)
+            .into_iter()
+            .find(|path| path.is_file())
+    }
+
+    pub fn read(&self, day: u8, name: Option<&str>) -> Result<String, AocError> {
+        if self.source == InputSource::Stdin {
+            let mut input = String::new();
+            return io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(|source| AocError::Io {
+                    path: PathBuf::from("<stdin>"),
+                    source,
+                });
+        }
+        let Some(path) = self.find(day, name) else {
+            if let (Some(fetcher), None, Some(dir)) = (&self.fetcher, name, self.primary_dir()) {
+                return fetcher.fetch(day, dir);
+            }
+            return Err(AocError::MissingInput {
+                day,
+                tried: self.candidates(day, name),
+                available: self.available(day),
+            });
+        };
+        fs::read_to_string(&path).map_err(|source| AocError::Io { path, source })
+    }
+
+    /// Names of the extra inputs available for a day, not including the main input.
+    pub fn available(&self, day: u8) -> Vec<String> {
+        let InputSource::Dirs(dirs) = &self.source else {
+            return Vec::new();
+        };
+        let prefix = format!("day{day}.");
+        let mut names: Vec<String> = dirs
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter_map(|file| {
+                let name = file.strip_prefix(&prefix)?.strip_suffix(".txt")?;
+                (!name.is_empty()).then(|| name.to_string())
+            })
+            .collect();
+        names.sort();
+        names.dedup();
+        names
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_inputs() {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day16.txt"), "main").unwrap();
+        fs::write(dir.join("day16.example2.txt"), "example").unwrap();
+
+        let inputs = Inputs::new(Some(dir.to_str().unwrap()));
+        assert_eq!("main", inputs.read(16, None).unwrap());
+        assert_eq!("example", inputs.read(16, Some("example2")).unwrap());
+        assert_eq!(vec!["example2"], inputs.available(16));
+
+        let err = inputs.read(16, Some("example3")).unwrap_err();
+        assert!(err.to_string().contains("day16.example3.txt"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
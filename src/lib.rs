@@ -1,7 +1,68 @@
-use std::{fmt::Debug, fs};
+use std::fmt::Debug;
 
-pub fn read_input(day: &str) -> Result<String, std::io::Error> {
-    fs::read_to_string(format!("inputs/{day}.txt"))
+pub mod answers;
+pub mod bench;
+pub mod days;
+mod error;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod search;
+
+pub use error::{AocError, ParseError, Unsolvable};
+pub use geometry::{Direction, Point, Vector};
+pub use grid::Grid;
+
+pub type Answer = String;
+
+/// A day's puzzle. The input is parsed once and the parsed value is shared by both parts.
+pub trait Solution {
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(parsed: &Self::Parsed) -> Result<Answer, Unsolvable>;
+
+    fn part2(parsed: &Self::Parsed) -> Result<Answer, Unsolvable>;
+
+    /// Parses the input, attributing any error to this day.
+    fn read(input: &str) -> Result<Self::Parsed, AocError> {
+        Self::parse(input).map_err(|source| AocError::Parse {
+            day: Self::DAY,
+            source,
+        })
+    }
+
+    /// Runs part 1 or 2, failing for any other part.
+    fn run(part: u8, parsed: &Self::Parsed) -> Result<Answer, AocError> {
+        let answer = match part {
+            1 => Self::part1(parsed),
+            2 => Self::part2(parsed),
+            _ => Err(Unsolvable::new(format!("there is no part {part}"))),
+        };
+        answer.map_err(|source| AocError::Unsolvable {
+            day: Self::DAY,
+            part,
+            source,
+        })
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer, AocError> {
+        Self::run(1, &Self::read(input)?)
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer, AocError> {
+        Self::run(2, &Self::read(input)?)
+    }
+}
+
+/// Reads a day's main input from the default location, see [`input::Inputs::new`].
+pub fn read_input(day: u8) -> Result<String, AocError> {
+    input::Inputs::new(None).read(day, None)
 }
 
 pub fn print_grid<T: Debug>(v: &Vec<Vec<T>>) {
@@ -9,3 +70,17 @@ pub fn print_grid<T: Debug>(v: &Vec<Vec<T>>) {
         println!("{:?}", r);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day1::Day1;
+
+    #[test]
+    fn test_run_rejects_other_parts() {
+        let parsed = Day1::read("3   4\n4   3").unwrap();
+        assert_eq!("7", Day1::run(2, &parsed).unwrap());
+        let error = Day1::run(3, &parsed).unwrap_err().to_string();
+        assert!(error.contains("there is no part 3"), "{error}");
+    }
+}
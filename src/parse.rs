@@ -0,0 +1,143 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::ParseError;
+
+/// A line of puzzle input along with its 1-based line number, used to attach
+/// context to parse errors.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.number, self.text, message)
+    }
+
+    pub fn parse<T>(&self, field: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        field
+            .trim()
+            .parse()
+            .map_err(|e| self.error(format!("invalid value `{}`: {e}", field.trim())))
+    }
+
+    /// Fails on the first character not contained in `allowed`.
+    pub fn expect_chars(&self, allowed: &str) -> Result<(), ParseError> {
+        match self.text.chars().find(|ch| !allowed.contains(*ch)) {
+            Some(ch) => Err(self.error(format!("unexpected character `{ch}`"))),
+            None => Ok(()),
+        }
+    }
+
+    /// Splits the line once on `delimiter`, failing if it is not present.
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(format!("expected `{delimiter}`")))
+    }
+}
+
+/// Numbered lines of `input`, skipping blank lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Line {
+            number: i + 1,
+            text,
+        })
+        .filter(|line| !line.text.trim().is_empty())
+}
+
+/// Trimmed rows of a rectangular character grid, failing on ragged rows or an empty grid.
+pub fn grid_lines(input: &str) -> Result<Vec<Line<'_>>, ParseError> {
+    let rows: Vec<Line> = lines(input)
+        .map(|line| Line {
+            text: line.text.trim(),
+            ..line
+        })
+        .collect();
+    let width = rows
+        .first()
+        .ok_or_else(|| ParseError::new("empty grid"))?
+        .text
+        .len();
+    if let Some(row) = rows.iter().find(|row| row.text.len() != width) {
+        return Err(row.error(format!(
+            "expected {width} columns, found {}",
+            row.text.len()
+        )));
+    }
+    Ok(rows)
+}
+
+/// Groups of consecutive non-blank lines, as used by puzzles with several input sections.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for (i, text) in input.lines().enumerate() {
+        if text.trim().is_empty() {
+            if !sections.last().unwrap().is_empty() {
+                sections.push(Vec::new());
+            }
+        } else {
+            sections.last_mut().unwrap().push(Line {
+                number: i + 1,
+                text,
+            });
+        }
+    }
+    if sections.last().unwrap().is_empty() {
+        sections.pop();
+    }
+    sections
+}
+
+/// Takes exactly `N` sections from the input, naming the missing one on failure.
+pub fn expect_sections<'a, const N: usize>(
+    input: &'a str,
+    names: [&str; N],
+) -> Result<[Vec<Line<'a>>; N], ParseError> {
+    let sections = sections(input);
+    if sections.len() < N {
+        return Err(ParseError::new(format!(
+            "missing {} section",
+            names[sections.len()]
+        )));
+    }
+    if let Some(extra) = sections.get(N) {
+        return Err(extra[0].error("unexpected extra section"));
+    }
+    Ok(sections.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\n\nc\n";
+        let sections = sections(input);
+        assert_eq!(2, sections.len());
+        assert_eq!(5, sections[1][0].number);
+    }
+
+    #[test]
+    fn test_grid_lines_ragged() {
+        let err = grid_lines("...\n..\n...").unwrap_err();
+        assert_eq!(Some(2), err.line);
+    }
+
+    #[test]
+    fn test_parse_error_has_line() {
+        let line = lines("1\n\nx").last().unwrap();
+        let err = line.parse::<u32>(line.text).unwrap_err();
+        assert_eq!(Some(3), err.line);
+        assert_eq!("x", err.text);
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Edge weights for [`dijkstra`] and [`astar`]. Weights must not be negative.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// Breadth-first search from `start`, returning the first node satisfying `is_goal`
+/// along with its distance in steps.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(N, usize)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some((node, steps));
+        }
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Distance in steps from `start` to every reachable node.
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let steps = distances[&node] + 1;
+        for next in neighbours(&node) {
+            distances.entry(next.clone()).or_insert_with(|| {
+                queue.push_back(next);
+                steps
+            });
+        }
+    }
+    distances
+}
+
+/// Result of a weighted search: the best known cost of every node reached and, for each,
+/// all the predecessors it can be reached from at that cost.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One optimal path from the start to `end`, both included.
+    pub fn path(&self, end: &N) -> Option<Vec<N>> {
+        self.costs.get(end)?;
+        let mut path = vec![end.clone()];
+        while *path.last().unwrap() != self.start {
+            path.push(self.predecessors(path.last().unwrap())[0].clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node lying on some optimal path from the start to one of `ends`.
+    pub fn nodes_on_paths(&self, ends: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = ends
+            .into_iter()
+            .filter(|end| self.costs.contains_key(end))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        nodes
+    }
+}
+
+/// Best-first search shared by Dijkstra and A*, stopping at the first goal popped.
+fn search<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Paths<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths {
+        start: start.clone(),
+        costs: HashMap::from([(start.clone(), C::default())]),
+        predecessors: HashMap::new(),
+    };
+    // The heap refers to nodes by index so that `N` doesn't need to be `Ord`.
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = vec![start];
+    let mut closed = HashSet::new();
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = nodes[index].clone();
+        if paths.costs[&node] < cost || !closed.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return (paths, Some(node));
+        }
+        for (next, weight) in neighbours(&node) {
+            let next_cost = cost + weight;
+            match paths.costs.get(&next) {
+                Some(&best) if best < next_cost => continue,
+                Some(&best) if best == next_cost => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            paths.costs.insert(next.clone(), next_cost);
+            paths.predecessors.insert(next.clone(), vec![node.clone()]);
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                nodes.len(),
+            )));
+            nodes.push(next);
+        }
+    }
+    (paths, None)
+}
+
+/// Cheapest path from `start` to a node satisfying `is_goal`, returning that node and its cost.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(N, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = search(start, neighbours, |_| C::default(), is_goal);
+    let goal = goal?;
+    let cost = paths.costs[&goal];
+    Some((goal, cost))
+}
+
+/// Explores everything reachable from `start`, keeping all optimal predecessors so
+/// that every shortest path can be recovered with [`Paths::nodes_on_paths`].
+pub fn dijkstra_all<N, C, I>(start: N, neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, neighbours, |_| C::default(), |_| false).0
+}
+
+/// A* search guided by `heuristic`, which must never overestimate the remaining cost
+/// (and be consistent, e.g. Manhattan distance on a grid). Returns the path and its cost.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (paths, goal) = search(start, neighbours, heuristic, is_goal);
+    let goal = goal?;
+    Some((paths.path(&goal)?, paths.costs[&goal]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    const MAZE: &str = "\
+.....
+.###.
+.#...
+.#.#.
+...#.";
+
+    #[test]
+    fn test_searches_agree() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let (start, end) = (Point::new(0, 0), Point::new(4, 4));
+        let open = |p: &Point| {
+            grid.neighbours4(*p)
+                .filter(|&next| grid[next] == b'.')
+                .collect::<Vec<_>>()
+        };
+        let weighted = |p: &Point| open(p).into_iter().map(|next| (next, 1));
+
+        assert_eq!(Some((end, 8)), bfs(start, open, |&p| p == end));
+        assert_eq!(Some(&8), bfs_distances(start, open).get(&end));
+        assert_eq!(Some((end, 8)), dijkstra(start, weighted, |&p| p == end));
+
+        let (path, cost) = astar(start, weighted, |p| p.manhattan(end), |&p| p == end).unwrap();
+        assert_eq!(8, cost);
+        assert_eq!(9, path.len());
+        assert_eq!((start, end), (path[0], path[8]));
+    }
+
+    #[test]
+    fn test_all_optimal_paths() {
+        // Two equally cheap routes from 0 to 3, and a more expensive one through 4.
+        let edges = |n: &u32| match n {
+            0 => vec![(1, 1), (2, 1), (4, 1)],
+            1 | 2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        };
+        let paths = dijkstra_all(0, edges);
+        assert_eq!(Some(2), paths.cost(&3));
+        assert_eq!(HashSet::from([0, 1, 2, 3]), paths.nodes_on_paths([3]));
+        assert_eq!(3, paths.path(&3).unwrap().len());
+    }
+}